@@ -0,0 +1,101 @@
+use othello_lib::{board::Board, disc::Disc, player::Player};
+use std::cell::RefCell;
+
+/// A computer player whose search depth can be tuned between moves.
+pub trait DepthLimited: Player {
+    fn depth(&self) -> u32;
+    fn set_depth(&mut self, depth: u32);
+}
+
+/// Maps the computer's current disc margin to a change in search depth.
+/// Far ahead means searching shallower, far behind means searching deeper.
+pub fn depth_adjustment(margin: isize) -> i32 {
+    match margin {
+        m if m >= 20 => -2,
+        m if m >= 8 => -1,
+        m if m <= -20 => 2,
+        m if m <= -8 => 1,
+        _ => 0,
+    }
+}
+
+/// Wraps a depth-limited player and retunes its depth before every move to keep games close.
+pub struct AdaptivePlayer<P: DepthLimited> {
+    inner: RefCell<P>,
+    base_depth: u32,
+    min_depth: u32,
+    max_depth: u32,
+}
+
+impl<P: DepthLimited> AdaptivePlayer<P> {
+    pub fn new(inner: P, min_depth: u32, max_depth: u32) -> Self {
+        let base_depth = inner.depth().clamp(min_depth, max_depth);
+        AdaptivePlayer {
+            inner: RefCell::new(inner),
+            base_depth,
+            min_depth,
+            max_depth,
+        }
+    }
+
+    pub fn current_depth(&self) -> u32 {
+        self.inner.borrow().depth()
+    }
+}
+
+impl<P: DepthLimited> Player for AdaptivePlayer<P> {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        let margin = board.count_discs(disc) as isize - board.count_discs(disc.opposite()) as isize;
+        let depth = (self.base_depth as i64 + depth_adjustment(margin) as i64)
+            .clamp(self.min_depth as i64, self.max_depth as i64) as u32;
+        self.inner.borrow_mut().set_depth(depth);
+        self.inner.borrow().select_move(board, disc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedDepth(u32);
+    impl Player for FixedDepth {
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            board.valid_moves(disc).first().copied()
+        }
+    }
+    impl DepthLimited for FixedDepth {
+        fn depth(&self) -> u32 {
+            self.0
+        }
+        fn set_depth(&mut self, depth: u32) {
+            self.0 = depth;
+        }
+    }
+
+    #[test]
+    fn depth_adjustment_policy() {
+        let cases = [
+            (-64, 2),
+            (-20, 2),
+            (-19, 1),
+            (-8, 1),
+            (-7, 0),
+            (0, 0),
+            (7, 0),
+            (8, -1),
+            (19, -1),
+            (20, -2),
+            (64, -2),
+        ];
+        for &(margin, delta) in &cases {
+            assert_eq!(depth_adjustment(margin), delta, "margin {}", margin);
+        }
+    }
+
+    #[test]
+    fn adaptive_player_keeps_depth_within_bounds() {
+        let player = AdaptivePlayer::new(FixedDepth(9), 1, 6);
+        player.select_move(&Board::new(), Disc::Black);
+        assert_eq!(player.current_depth(), 6);
+    }
+}
@@ -1,9 +1,40 @@
 use othello_lib::player::Player;
 
+/// Always plays the first legal move. For self-play that varies from game to game,
+/// use `RandomPlayer::with_seed` instead.
 pub struct ComputerPlayer;
 
 impl Player for ComputerPlayer {
-    fn select_move(&self, board: &othello_lib::board::Board, disc: othello_lib::disc::Disc) -> usize {
-        board.valid_moves(disc)[0]
+    fn select_move(
+        &self,
+        board: &othello_lib::board::Board,
+        disc: othello_lib::disc::Disc,
+    ) -> Option<usize> {
+        board.valid_moves(disc).first().copied()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use othello_lib::disc::Disc;
+    use othello_lib::game::Game;
+
+    #[test]
+    fn passes_without_legal_moves() {
+        let mut game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        game.run().unwrap();
+        assert!(game.is_over());
+        assert_eq!(ComputerPlayer.select_move(game.board(), Disc::Black), None);
+        assert_eq!(ComputerPlayer.select_move(game.board(), Disc::White), None);
+    }
+
+    #[test]
+    fn plays_first_legal_move() {
+        let game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        assert_eq!(
+            ComputerPlayer.select_move(game.board(), Disc::Black),
+            Some(19)
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use othello_lib::board::Board;
+use othello_lib::disc::Disc;
+use othello_lib::player::Player;
+
+/// Plays the move that flips the most discs, preferring the lowest index on ties.
+pub struct GreedyPlayer;
+
+impl Player for GreedyPlayer {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        board
+            .valid_moves(disc)
+            .into_iter()
+            .rev()
+            .max_by_key(|&index| board.flip_count(index, disc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use othello_lib::board::StandardBoard;
+
+    #[test]
+    fn picks_most_flips() {
+        let mut board = StandardBoard::new();
+        for (index, disc) in [(19, Disc::Black), (20, Disc::White), (21, Disc::Black)] {
+            board.apply_move(index, disc).unwrap();
+        }
+        let counts: Vec<(usize, usize)> = board
+            .valid_moves(Disc::White)
+            .into_iter()
+            .map(|index| (index, board.flip_count(index, Disc::White)))
+            .collect();
+        assert_eq!(counts, [(12, 2), (18, 1), (34, 1)]);
+        assert_eq!(GreedyPlayer.select_move(&board, Disc::White), Some(12));
+    }
+
+    #[test]
+    fn ties_go_to_lowest_index() {
+        let board = StandardBoard::new();
+        assert_eq!(GreedyPlayer.select_move(&board, Disc::Black), Some(19));
+    }
+}
@@ -1,6 +1,33 @@
-use othello_lib::{board::Board, disc::Disc, player::Player};
+use othello_lib::{
+    board::{Board, StandardBoard},
+    disc::Disc,
+    player::Player,
+};
 use std::io::{self, Write};
 
+/// Something the person at the keyboard asked for other than a move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Request {
+    /// Take back the last move pair.
+    Undo,
+    /// Stop playing and leave the game.
+    Quit,
+}
+
+/// What a line typed at the move prompt asks for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// A legal move at this index.
+    Move(usize),
+    Pass,
+    Undo,
+    /// List the legal moves and prompt again.
+    ShowMoves,
+    Quit,
+    /// Neither a known command nor a legal move.
+    Invalid,
+}
+
 /// Reads moves from stdin for a human player.
 pub struct HumanPlayer;
 
@@ -8,27 +35,142 @@ impl HumanPlayer {
     pub fn new() -> Self {
         HumanPlayer
     }
-}
 
-impl Player for HumanPlayer {
-    fn select_move(&self, board: &Board, disc: Disc) -> usize {
+    /// Prompts until the human enters a legal move for `disc`, or returns `None` at
+    /// once when `disc` has to pass. A request typed instead of a move comes back as
+    /// the error, so the caller can act on it before anything reaches the game.
+    pub fn read_turn(&self, board: &Board, disc: Disc) -> Result<Option<usize>, Request> {
+        if board.valid_moves(disc).is_empty() {
+            return Ok(None);
+        }
         loop {
             println!("{}", board);
 
             // let moves = board.all_flips(0, disc).unwrap_or_default(); // placeholder
-            print!("Enter move for {:?}: ", disc);
+            print!("Enter move for {:?} (e.g. d3, or moves, undo, quit): ", disc);
             io::stdout().flush().unwrap();
 
             let mut input = String::new();
             if io::stdin().read_line(&mut input).is_err() {
                 continue;
             }
-            if let Ok(idx) = input.trim().parse::<usize>() {
-                if board.is_valid_move(idx, disc) {
-                    return idx;
-                }
+            match parse_command(&input, board, disc) {
+                Command::Move(idx) => return Ok(Some(idx)),
+                Command::Pass => println!("You have a legal move, so you can't pass."),
+                Command::Undo => return Err(Request::Undo),
+                Command::ShowMoves => println!("Legal moves: {}", legal_moves(board, disc)),
+                Command::Quit => return Err(Request::Quit),
+                Command::Invalid => println!("Invalid move, try again."),
             }
-            println!("Invalid move, try again.");
         }
     }
-}
\ No newline at end of file
+}
+
+impl Default for HumanPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Asked through `Game::step`, the human can only move: undo and quit need the CLI
+/// loop, which calls `read_turn` itself.
+impl Player for HumanPlayer {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        loop {
+            match self.read_turn(board, disc) {
+                Ok(choice) => return choice,
+                Err(request) => println!("{:?} is not available here.", request),
+            }
+        }
+    }
+}
+
+/// Works out what a line typed at the move prompt asks for. Commands ignore case and
+/// surrounding whitespace, and an empty line passes.
+pub fn parse_command(input: &str, board: &Board, disc: Disc) -> Command {
+    let input = input.trim();
+    if input.is_empty() || input.eq_ignore_ascii_case("pass") {
+        return Command::Pass;
+    }
+    if input.eq_ignore_ascii_case("undo") {
+        return Command::Undo;
+    }
+    if input.eq_ignore_ascii_case("moves") {
+        return Command::ShowMoves;
+    }
+    if input.eq_ignore_ascii_case("quit") {
+        return Command::Quit;
+    }
+    parse_move(input, board, disc).map_or(Command::Invalid, Command::Move)
+}
+
+/// `disc`'s legal moves in algebraic notation, sorted and separated by spaces.
+fn legal_moves(board: &Board, disc: Disc) -> String {
+    let mut moves: Vec<String> = board
+        .valid_moves(disc)
+        .into_iter()
+        .map(|index| {
+            StandardBoard::algebraic_from_index(index).expect("legal moves are on the board")
+        })
+        .collect();
+    moves.sort();
+    moves.join(" ")
+}
+
+/// Reads `input` as algebraic notation (`"d3"`) or else a raw index (`"19"`),
+/// returning the index only if it is a legal move for `disc`.
+pub fn parse_move(input: &str, board: &Board, disc: Disc) -> Option<usize> {
+    let input = input.trim();
+    let idx = StandardBoard::index_from_algebraic(input)
+        .ok()
+        .or_else(|| input.parse::<usize>().ok())?;
+    board.is_valid_move(idx, disc).then_some(idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_move_accepts_algebraic_and_index() {
+        let board = StandardBoard::new();
+        assert_eq!(parse_move("d3", &board, Disc::Black), Some(19));
+        assert_eq!(parse_move("D3", &board, Disc::Black), Some(19));
+        assert_eq!(parse_move("19", &board, Disc::Black), Some(19));
+        assert_eq!(parse_move(" f5\n", &board, Disc::Black), Some(37));
+    }
+
+    #[test]
+    fn parse_move_rejects_bad_input() {
+        let board = StandardBoard::new();
+        assert_eq!(parse_move("z9", &board, Disc::Black), None);
+        assert_eq!(parse_move("a1", &board, Disc::Black), None);
+        assert_eq!(parse_move("d3", &board, Disc::White), None);
+        assert_eq!(parse_move("64", &board, Disc::Black), None);
+        assert_eq!(parse_move("move", &board, Disc::Black), None);
+    }
+
+    #[test]
+    fn parse_command_recognizes_commands() {
+        let board = StandardBoard::new();
+        let parse = |input| parse_command(input, &board, Disc::Black);
+        assert_eq!(parse("d3"), Command::Move(19));
+        assert_eq!(parse("19\n"), Command::Move(19));
+        assert_eq!(parse(""), Command::Pass);
+        assert_eq!(parse("PASS"), Command::Pass);
+        assert_eq!(parse("undo"), Command::Undo);
+        assert_eq!(parse("moves"), Command::ShowMoves);
+        assert_eq!(parse(" Moves \n"), Command::ShowMoves);
+        assert_eq!(parse("quit"), Command::Quit);
+        assert_eq!(parse(" QUIT\n"), Command::Quit);
+        assert_eq!(parse("a1"), Command::Invalid);
+        assert_eq!(parse("move"), Command::Invalid);
+    }
+
+    #[test]
+    fn legal_moves_are_listed_sorted() {
+        let board = StandardBoard::new();
+        assert_eq!(legal_moves(&board, Disc::Black), "c4 d3 e6 f5");
+        assert_eq!(legal_moves(&board, Disc::White), "c5 d6 e3 f4");
+    }
+}
@@ -1,16 +1,365 @@
-use othello_lib::game::Game;
+use othello_lib::disc::Disc;
+use othello_lib::game::{Game, Turn};
+use othello_lib::player::Player;
 use std::error::Error;
+use std::io;
+use std::time::Duration;
 
+pub mod adaptive;
 pub mod human;
+pub mod montecarlo;
 pub mod computer;
+pub mod greedy;
+pub mod random;
+pub mod replay;
+pub mod search;
+pub mod spectate;
+
+/// Deepest search the CLI will run; deeper searches take minutes per move.
+pub const MAX_DEPTH: u32 = 10;
+
+/// Who sits at the board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// A human against the computer.
+    HumanVsAi,
+    /// Two computer players, shown move by move.
+    AiVsAi,
+}
+
+/// Command-line options for the CLI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// Replay the finished game once it ends.
+    pub replay: bool,
+    /// Delay between frames when replaying.
+    pub speed: Duration,
+    /// The color the human plays.
+    pub side: Disc,
+    pub mode: Mode,
+    /// Search depth of the computer, 0 for the plain first-move player.
+    pub depth: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            replay: false,
+            speed: Duration::from_millis(500),
+            side: Disc::Black,
+            mode: Mode::HumanVsAi,
+            depth: 0,
+        }
+    }
+}
+
+impl Options {
+    /// Parses the arguments following the program name.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--replay" => options.replay = true,
+                "--speed" => {
+                    let value = args.next().ok_or("--speed expects a delay in milliseconds")?;
+                    let millis = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid --speed value: {}", value))?;
+                    options.speed = Duration::from_millis(millis);
+                }
+                "--side" => {
+                    let value = args.next().ok_or("--side expects black or white")?;
+                    options.side = match value.to_ascii_lowercase().as_str() {
+                        "black" => Disc::Black,
+                        "white" => Disc::White,
+                        _ => return Err(format!("invalid --side value: {}", value)),
+                    };
+                }
+                "--mode" => {
+                    let value = args.next().ok_or("--mode expects human or ai-vs-ai")?;
+                    options.mode = match value.as_str() {
+                        "human" => Mode::HumanVsAi,
+                        "ai-vs-ai" => Mode::AiVsAi,
+                        _ => return Err(format!("invalid --mode value: {}", value)),
+                    };
+                }
+                "--depth" => {
+                    let value = args.next().ok_or("--depth expects a number of plies")?;
+                    options.depth = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|&depth| depth <= MAX_DEPTH)
+                        .ok_or_else(|| {
+                            format!(
+                                "invalid --depth value: {} (expected 0 to {})",
+                                value, MAX_DEPTH
+                            )
+                        })?;
+                }
+                other => return Err(format!("unknown argument: {}", other)),
+            }
+        }
+        Ok(options)
+    }
+}
 
 /// Runs the Othello CLI game loop.
 /// Returns an error if I/O or game logic fails.
 pub fn run() -> Result<(), Box<dyn Error>> {
-    let human = Box::new(human::HumanPlayer::new());
-    let computer = Box::new(computer::ComputerPlayer);
+    let options = Options::parse(std::env::args().skip(1))?;
+
+    if options.mode == Mode::AiVsAi {
+        // --speed sets the pause between moves while spectating
+        let mut game = Game::new(
+            computer_with_depth(options.depth),
+            computer_with_depth(options.depth),
+        );
+        spectate::spectate(&mut game, options.speed, &mut io::stdout())?;
+        return Ok(());
+    }
+
+    let human = human::HumanPlayer::new();
+    let computer = computer_with_depth(options.depth);
 
-    let mut game = Game::new(human, computer);
-    game.run();
+    let (black, white) = assign_sides(options.side, Box::new(&human), computer);
+    let mut game = Game::new(black, white);
+    while !game.is_over() {
+        if game.current_disc() != options.side {
+            game.step()?;
+            continue;
+        }
+        // the human's turn is read here rather than through `step`, so undo and quit
+        // never reach the game as moves
+        match human.read_turn(game.board(), options.side) {
+            Ok(Some(index)) => game.play(index)?,
+            Ok(None) => game.pass()?,
+            Err(request) => {
+                if !handle_request(&mut game, request, options.side) {
+                    println!("You resigned. {:?} wins.", options.side.opposite());
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    if options.replay {
+        replay::replay(&game, options.speed, &mut io::stdout())?;
+    }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// The computer opponent for `--depth`: an alpha-beta search of that many plies,
+/// or the first-legal-move player for depth 0.
+pub fn computer_with_depth(depth: u32) -> Box<dyn Player> {
+    match depth {
+        0 => Box::new(computer::ComputerPlayer),
+        depth => Box::new(search::SearchPlayer::alpha_beta(depth)),
+    }
+}
+
+/// How strong a computer opponent to offer, without naming the player behind it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    /// Plays the first legal move.
+    Easy,
+    /// Plays the move that flips the most discs.
+    Medium,
+    /// Searches six plies ahead with alpha-beta.
+    Hard,
+}
+
+/// The computer opponent for `difficulty`.
+pub fn computer_for(difficulty: Difficulty) -> Box<dyn Player> {
+    match difficulty {
+        Difficulty::Easy => Box::new(computer::ComputerPlayer),
+        Difficulty::Medium => Box::new(greedy::GreedyPlayer),
+        Difficulty::Hard => Box::new(search::SearchPlayer::alpha_beta(6)),
+    }
+}
+
+/// Orders the players as `(black, white)` with the human playing `side`.
+pub fn assign_sides<'p>(
+    side: Disc,
+    human: Box<dyn Player + 'p>,
+    computer: Box<dyn Player + 'p>,
+) -> (Box<dyn Player + 'p>, Box<dyn Player + 'p>) {
+    match side {
+        Disc::Black => (human, computer),
+        Disc::White => (computer, human),
+    }
+}
+
+/// Acts on a request the human playing `side` made instead of a move. Quitting
+/// resigns the game for `side`, whoever is to move. Returns `false` if the game
+/// should stop.
+pub fn handle_request(game: &mut Game, request: human::Request, side: Disc) -> bool {
+    match request {
+        human::Request::Undo => {
+            if !take_back(game, side) {
+                println!("Nothing to undo.");
+            }
+            true
+        }
+        human::Request::Quit => {
+            game.resign(side);
+            false
+        }
+    }
+}
+
+/// Undoes turns up to and including `side`'s most recent move, so `side` can play it again.
+/// Returns `false` if `side` had not moved yet, leaving the game at its start, or if
+/// the game was conceded and can't be rewound.
+pub fn take_back(game: &mut Game, side: Disc) -> bool {
+    while let Some(&turn) = game.history().last() {
+        if game.undo_last().is_err() {
+            return false;
+        }
+        if matches!(turn, Turn::Move { disc, .. } if disc == side) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use computer::ComputerPlayer;
+    use othello_lib::game::GameOutcome;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_options() {
+        assert_eq!(Options::parse(args(&[])), Ok(Options::default()));
+        assert_eq!(
+            Options::parse(args(&["--replay", "--speed", "50"])),
+            Ok(Options {
+                replay: true,
+                speed: Duration::from_millis(50),
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            Options::parse(args(&["--side", "White"])).map(|o| o.side),
+            Ok(Disc::White)
+        );
+        assert!(Options::parse(args(&["--side"])).is_err());
+        assert!(Options::parse(args(&["--side", "red"])).is_err());
+        assert_eq!(
+            Options::parse(args(&["--mode", "ai-vs-ai"])).map(|o| o.mode),
+            Ok(Mode::AiVsAi)
+        );
+        assert!(Options::parse(args(&["--mode", "robots"])).is_err());
+        assert_eq!(
+            Options::parse(args(&["--depth", "4"])).map(|o| o.depth),
+            Ok(4)
+        );
+        assert!(Options::parse(args(&["--depth", "11"])).is_err());
+        assert!(Options::parse(args(&["--depth", "-1"])).is_err());
+        assert!(Options::parse(args(&["--depth"])).is_err());
+        assert!(Options::parse(args(&["--speed"])).is_err());
+        assert!(Options::parse(args(&["--speed", "fast"])).is_err());
+        assert!(Options::parse(args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn take_back_reverts_move_pair() {
+        let mut game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        game.step().unwrap();
+        let before = game.board().clone();
+        game.step().unwrap();
+        game.step().unwrap();
+
+        // white asks to undo on its next turn
+        assert!(take_back(&mut game, Disc::White));
+        assert_eq!(game.board(), &before);
+        assert_eq!(game.current_disc(), Disc::White);
+
+        assert!(!take_back(&mut game, Disc::White));
+        assert!(game.history().is_empty());
+    }
+
+    /// Always answers with the same square, so tests can tell players apart.
+    struct Marker(usize);
+    impl Player for Marker {
+        fn select_move(&self, _: &othello_lib::board::Board, _: Disc) -> Option<usize> {
+            Some(self.0)
+        }
+    }
+
+    #[test]
+    fn assign_sides_places_human() {
+        let board = othello_lib::board::StandardBoard::new();
+        for (side, expected) in [(Disc::Black, (1, 2)), (Disc::White, (2, 1))] {
+            let (black, white) = assign_sides(side, Box::new(Marker(1)), Box::new(Marker(2)));
+            assert_eq!(black.select_move(&board, Disc::Black), Some(expected.0));
+            assert_eq!(white.select_move(&board, Disc::White), Some(expected.1));
+        }
+    }
+
+    #[test]
+    fn computer_with_depth_picks_player() {
+        let game = Game::from_transcript(
+            Box::new(ComputerPlayer),
+            Box::new(ComputerPlayer),
+            "d3 c3 b3 f4 f5",
+        )
+        .unwrap();
+        let (board, disc) = (game.board(), game.current_disc());
+        assert_eq!(game.available_moves(), [9, 11, 43, 45]);
+        // depth 0 takes the first legal move, a search finds the strongest reply
+        assert_eq!(computer_with_depth(0).select_move(board, disc), Some(9));
+        assert_eq!(computer_with_depth(1).select_move(board, disc), Some(43));
+        assert_eq!(
+            computer_with_depth(3).select_move(board, disc),
+            search::SearchPlayer::minimax(3).select_move(board, disc)
+        );
+    }
+
+    #[test]
+    fn computer_for_plays_legal_moves() {
+        let board = othello_lib::board::StandardBoard::new();
+        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            let choice = computer_for(difficulty).select_move(&board, Disc::Black);
+            assert!(
+                choice.is_some_and(|index| board.is_valid_move(index, Disc::Black)),
+                "{:?} chose {:?}",
+                difficulty,
+                choice
+            );
+        }
+    }
+
+    #[test]
+    fn handle_request_quit_resigns() {
+        let mut game = Game::from_transcript(
+            Box::new(ComputerPlayer),
+            Box::new(ComputerPlayer),
+            "d3",
+        )
+        .unwrap();
+        assert!(!handle_request(&mut game, human::Request::Quit, Disc::White));
+        // nothing is recorded for the request, and white has resigned
+        assert_eq!(game.to_transcript(), "d3");
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::Black)));
+    }
+
+    #[test]
+    fn handle_request_quit_resigns_side_not_mover() {
+        // white quits while black is to move
+        let mut game = Game::from_transcript(
+            Box::new(ComputerPlayer),
+            Box::new(ComputerPlayer),
+            "d3 c3",
+        )
+        .unwrap();
+        assert!(!handle_request(&mut game, human::Request::Quit, Disc::White));
+        assert_eq!(game.to_transcript(), "d3 c3");
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::Black)));
+    }
+}
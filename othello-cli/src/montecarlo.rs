@@ -0,0 +1,105 @@
+use crate::random::XorShift;
+use othello_lib::board::Board;
+use othello_lib::disc::Disc;
+use othello_lib::player::Player;
+
+/// Scores each legal move by playing `rollouts` random games from it, and picks the
+/// move that wins most often. Results only depend on `seed` and the position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonteCarloPlayer {
+    pub rollouts: u32,
+    pub seed: u64,
+}
+
+impl MonteCarloPlayer {
+    pub fn new(rollouts: u32, seed: u64) -> Self {
+        MonteCarloPlayer { rollouts, seed }
+    }
+
+    /// Points scored by `disc` over the rollouts after playing `index`: two per win, one per tie.
+    fn rollout_score(&self, board: &Board, index: usize, disc: Disc, rng: &mut XorShift) -> u64 {
+        let mut start = board.clone();
+        start
+            .apply_move(index, disc)
+            .expect("valid moves can always be applied");
+        (0..self.rollouts)
+            .map(|_| {
+                let end = random_playout(start.clone(), disc.opposite(), rng);
+                let (own, other) = (end.count_discs(disc), end.count_discs(disc.opposite()));
+                match own.cmp(&other) {
+                    std::cmp::Ordering::Greater => 2,
+                    std::cmp::Ordering::Equal => 1,
+                    std::cmp::Ordering::Less => 0,
+                }
+            })
+            .sum()
+    }
+}
+
+/// Plays uniformly random legal moves, starting with `disc`, until neither side can move.
+fn random_playout(mut board: Board, mut disc: Disc, rng: &mut XorShift) -> Board {
+    loop {
+        let moves = board.valid_moves(disc);
+        if moves.is_empty() {
+            if board.valid_moves(disc.opposite()).is_empty() {
+                return board;
+            }
+        } else {
+            let index = moves[rng.below(moves.len())];
+            board
+                .apply_move(index, disc)
+                .expect("valid moves can always be applied");
+        }
+        disc = disc.opposite();
+    }
+}
+
+impl Player for MonteCarloPlayer {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        let mut rng = XorShift::new(self.seed ^ board.zobrist_hash());
+        let mut best: Option<(usize, u64)> = None;
+        for index in board.valid_moves(disc) {
+            let score = self.rollout_score(board, index, disc, &mut rng);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((index, score));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer::ComputerPlayer;
+    use othello_lib::game::Game;
+
+    #[test]
+    fn finds_the_winning_endgame_move() {
+        // six empties left; black wins by 10 playing 41 and loses by 12 playing 16
+        let game = Game::from_transcript(
+            Box::new(ComputerPlayer),
+            Box::new(ComputerPlayer),
+            "f5 f4 f3 f6 d3 c3 e6 g4 f7 f8 g5 d6 d7 c8 g8 c5 g7 h5 e8 e2 c6 d2 f2 g2 b4 g6 \
+             b2 b7 h4 c4 b5 h8 h1 e7 f1 a1 e3 h2 g1 h3 d8 a4 h7 c7 b3 a5 a7 a6 h6 g3 c2 a2 \
+             b8 a8",
+        )
+        .unwrap();
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert_eq!(game.available_moves(), [16, 41]);
+
+        let player = MonteCarloPlayer::new(50, 7);
+        assert_eq!(player.select_move(game.board(), Disc::Black), Some(41));
+        assert_eq!(player.select_move(game.board(), Disc::Black), Some(41));
+        let other_seed = MonteCarloPlayer::new(50, 12345);
+        assert_eq!(other_seed.select_move(game.board(), Disc::Black), Some(41));
+    }
+
+    #[test]
+    fn passes_without_legal_moves() {
+        let mut game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        game.run().unwrap();
+        let player = MonteCarloPlayer::new(10, 1);
+        assert_eq!(player.select_move(game.board(), Disc::Black), None);
+    }
+}
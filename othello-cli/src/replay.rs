@@ -0,0 +1,42 @@
+use othello_lib::{board::Board, game::Game};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// The boards shown when replaying `game`, one per history entry plus the opening.
+pub fn frames(game: &Game) -> Vec<Board> {
+    (0..=game.history().len())
+        .filter_map(|ply| game.board_at(ply))
+        .collect()
+}
+
+/// Prints every frame of `game` to `out`, sleeping `delay` between frames.
+pub fn replay(game: &Game, delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    for (ply, board) in frames(game).iter().enumerate() {
+        if ply > 0 {
+            thread::sleep(delay);
+        }
+        writeln!(out, "{}", board)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer::ComputerPlayer;
+
+    #[test]
+    fn frames_follow_history() {
+        let mut game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        game.run().unwrap();
+
+        let frames = frames(&game);
+        assert_eq!(frames.len(), game.history().len() + 1);
+        assert_eq!(frames[0], Board::new());
+        assert_eq!(frames.last(), Some(game.board()));
+        for (ply, frame) in frames.iter().enumerate() {
+            assert_eq!(Some(frame), game.board_at(ply).as_ref());
+        }
+    }
+}
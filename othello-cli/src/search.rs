@@ -0,0 +1,467 @@
+use crate::adaptive::DepthLimited;
+use othello_lib::board::{Board, StandardBoard};
+use othello_lib::disc::Disc;
+use othello_lib::evaluator::{DiscDifference, Evaluator};
+use othello_lib::player::Player;
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Mixed into the table key when white is to move, since the board hash leaves out
+/// whose turn it is.
+const WHITE_TO_MOVE: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Positions a timed search visits between looks at the clock.
+const DEADLINE_CHECK_NODES: u64 = 1024;
+
+/// How a stored value relates to the position's true value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    /// The search failed high; the true value is at least this.
+    Lower,
+    /// The search failed low; the true value is at most this.
+    Upper,
+}
+
+/// A transposition table entry: what a search of `depth` plies found.
+#[derive(Clone, Copy, Debug)]
+struct TTEntry {
+    depth: u32,
+    value: i32,
+    bound: Bound,
+}
+
+/// Searches `depth` plies ahead with negamax, scoring leaves with an `Evaluator`.
+pub struct SearchPlayer<E: Evaluator = DiscDifference> {
+    depth: u32,
+    pruning: bool,
+    nodes: Cell<u64>,
+    budget: Option<Duration>,
+    reached: Cell<u32>,
+    table: Option<RefCell<HashMap<u64, TTEntry>>>,
+    evaluator: E,
+}
+
+impl SearchPlayer {
+    /// A plain negamax search that visits every node.
+    pub fn minimax(depth: u32) -> Self {
+        SearchPlayer {
+            depth,
+            pruning: false,
+            nodes: Cell::new(0),
+            budget: None,
+            reached: Cell::new(0),
+            table: None,
+            evaluator: DiscDifference,
+        }
+    }
+
+    /// A negamax search with alpha-beta pruning. Finds moves of the same value as
+    /// `minimax` at the same depth while visiting fewer nodes.
+    pub fn alpha_beta(depth: u32) -> Self {
+        SearchPlayer {
+            pruning: true,
+            ..Self::minimax(depth)
+        }
+    }
+}
+
+impl<E: Evaluator> SearchPlayer<E> {
+    /// The same search, scoring leaves with `evaluator` instead.
+    pub fn with_evaluator<F: Evaluator>(self, evaluator: F) -> SearchPlayer<F> {
+        SearchPlayer {
+            depth: self.depth,
+            pruning: self.pruning,
+            nodes: self.nodes,
+            budget: self.budget,
+            reached: self.reached,
+            table: self.table,
+            evaluator,
+        }
+    }
+
+    /// The same search, deepening one ply at a time up to its depth and stopping early
+    /// once `budget` runs out. Plays the best move of the deepest search that finished;
+    /// the first ply always finishes, however small the budget. A search still under way
+    /// at the deadline is abandoned within about a thousand positions.
+    pub fn with_time_budget(self, budget: Duration) -> Self {
+        SearchPlayer {
+            budget: Some(budget),
+            ..self
+        }
+    }
+
+    /// The same search, remembering positions it has valued so that one reached again
+    /// through a different move order is not searched twice.
+    pub fn with_transposition_table(self) -> Self {
+        SearchPlayer {
+            table: Some(RefCell::new(HashMap::new())),
+            ..self
+        }
+    }
+
+    /// Depth of the deepest search the most recent move completed.
+    pub fn depth_reached(&self) -> u32 {
+        self.reached.get()
+    }
+
+    /// Number of positions visited by the most recent search.
+    pub fn nodes_searched(&self) -> u64 {
+        self.nodes.get()
+    }
+
+    /// The best move for `disc`, preferring the lowest index among equal scores.
+    /// Returns `None` when `disc` has no legal move.
+    pub fn best_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        self.search(board, disc).map(|(index, _)| index)
+    }
+
+    /// Like `best_move`, but also returns the move's value from `disc`'s point of view.
+    pub fn search(&self, board: &Board, disc: Disc) -> Option<(usize, i32)> {
+        self.nodes.set(0);
+        if let Some(table) = &self.table {
+            table.borrow_mut().clear();
+        }
+        let Some(budget) = self.budget else {
+            self.reached.set(self.depth);
+            return self.search_to(board, disc, self.depth, None);
+        };
+        let deadline = Instant::now() + budget;
+        let mut best = self.search_to(board, disc, 1, None)?;
+        self.reached.set(1);
+        // searching past the last empty square finds nothing new
+        for depth in 2..=self.depth.min(board.empty_count() as u32) {
+            match self.search_to(board, disc, depth, Some(deadline)) {
+                Some(found) => best = found,
+                None => break,
+            }
+            self.reached.set(depth);
+        }
+        Some(best)
+    }
+
+    /// One search of `depth` plies, abandoned with `None` if `deadline` passes.
+    fn search_to(
+        &self,
+        board: &Board,
+        disc: Disc,
+        depth: u32,
+        deadline: Option<Instant>,
+    ) -> Option<(usize, i32)> {
+        let mut best: Option<(usize, i32)> = None;
+        let mut alpha = -i32::MAX;
+        // the root keeps index order so ties still go to the lowest index
+        for index in board.valid_moves(disc) {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return None;
+            }
+            let next = Self::after(board, index, disc);
+            let depth = depth.saturating_sub(1);
+            let value =
+                -self.negamax(&next, disc.opposite(), depth, -i32::MAX, -alpha, deadline)?;
+            if best.is_none_or(|(_, best_value)| value > best_value) {
+                best = Some((index, value));
+                if self.pruning {
+                    alpha = value;
+                }
+            }
+        }
+        best
+    }
+
+    /// Sorts `moves` so the likeliest good ones come first, which lets alpha-beta prune
+    /// sooner: corners, then moves flipping more discs. Equal moves keep their order.
+    pub fn order_moves(&self, board: &Board, disc: Disc, moves: &mut [usize]) {
+        moves.sort_by_key(|&index| {
+            (
+                Reverse(StandardBoard::CORNERS.contains(&index)),
+                Reverse(board.flip_count(index, disc)),
+            )
+        });
+    }
+
+    /// The value of `board` for `disc` searched `depth` plies deep, or `None` once
+    /// `deadline` has passed. Nothing is stored for a search cut short.
+    fn negamax(
+        &self,
+        board: &Board,
+        disc: Disc,
+        depth: u32,
+        mut alpha: i32,
+        mut beta: i32,
+        deadline: Option<Instant>,
+    ) -> Option<i32> {
+        let nodes = self.nodes.get() + 1;
+        self.nodes.set(nodes);
+        if nodes.is_multiple_of(DEADLINE_CHECK_NODES)
+            && deadline.is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return None;
+        }
+        if depth == 0 {
+            return Some(self.evaluator.score(board, disc));
+        }
+        let key = match disc {
+            Disc::Black => board.zobrist_hash(),
+            Disc::White => board.zobrist_hash() ^ WHITE_TO_MOVE,
+        };
+        if let Some(entry) = self.probe(key, depth) {
+            match entry.bound {
+                Bound::Exact => return Some(entry.value),
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return Some(entry.value);
+            }
+        }
+        let window = (alpha, beta);
+        let mut moves = board.valid_moves(disc);
+        if moves.is_empty() {
+            if board.valid_moves(disc.opposite()).is_empty() {
+                return Some(self.evaluator.score(board, disc));
+            }
+            // forced pass: the opponent moves again from the same position
+            let value =
+                -self.negamax(board, disc.opposite(), depth - 1, -beta, -alpha, deadline)?;
+            self.store(key, depth, value, window);
+            return Some(value);
+        }
+        if self.pruning {
+            self.order_moves(board, disc, &mut moves);
+        }
+        let mut best = i32::MIN;
+        for index in moves {
+            let next = Self::after(board, index, disc);
+            let value =
+                -self.negamax(&next, disc.opposite(), depth - 1, -beta, -alpha, deadline)?;
+            best = best.max(value);
+            if self.pruning {
+                alpha = alpha.max(value);
+                if alpha >= beta {
+                    break;
+                }
+            }
+        }
+        self.store(key, depth, best, window);
+        Some(best)
+    }
+
+    /// The table entry for `key` if it came from a search at least `depth` plies deep.
+    fn probe(&self, key: u64, depth: u32) -> Option<TTEntry> {
+        let table = self.table.as_ref()?.borrow();
+        table
+            .get(&key)
+            .copied()
+            .filter(|entry| entry.depth >= depth)
+    }
+
+    /// Records `value`, found by searching `depth` plies with the `(alpha, beta)` window.
+    fn store(&self, key: u64, depth: u32, value: i32, (alpha, beta): (i32, i32)) {
+        let Some(table) = &self.table else {
+            return;
+        };
+        let bound = if value <= alpha {
+            Bound::Upper
+        } else if value >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        table.borrow_mut().insert(
+            key,
+            TTEntry {
+                depth,
+                value,
+                bound,
+            },
+        );
+    }
+
+    fn after(board: &Board, index: usize, disc: Disc) -> Board {
+        let mut next = board.clone();
+        next.apply_move(index, disc)
+            .expect("valid moves can always be applied");
+        next
+    }
+}
+
+impl<E: Evaluator + Send> Player for SearchPlayer<E> {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        self.best_move(board, disc)
+    }
+}
+
+impl<E: Evaluator + Send> DepthLimited for SearchPlayer<E> {
+    fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: u32) {
+        self.depth = depth;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use othello_lib::evaluator::CornerWeighted;
+
+    fn play(moves: &[usize]) -> (StandardBoard, Disc) {
+        let mut board = StandardBoard::new();
+        let mut disc = Disc::Black;
+        for &index in moves {
+            board.apply_move(index, disc).unwrap();
+            disc = disc.opposite();
+        }
+        (board, disc)
+    }
+
+    #[test]
+    fn depth_one_maximizes_disc_count() {
+        let player = SearchPlayer::minimax(1);
+
+        let (board, disc) = play(&[19, 20, 21]);
+        assert_eq!(disc, Disc::White);
+        assert_eq!(player.select_move(&board, disc), Some(12));
+
+        // 43 and 45 both leave white with five discs; the lower index wins the tie
+        let (board, disc) = play(&[19, 18, 17, 29, 37]);
+        assert_eq!(&board.valid_moves(disc)[..], &[9, 11, 43, 45]);
+        assert_eq!(player.select_move(&board, disc), Some(43));
+    }
+
+    #[test]
+    fn never_returns_illegal_move() {
+        let players = [SearchPlayer::minimax(2), SearchPlayer::alpha_beta(3)];
+        let mut board = StandardBoard::new();
+        let mut disc = Disc::Black;
+        while !board.valid_moves(Disc::Black).is_empty()
+            || !board.valid_moves(Disc::White).is_empty()
+        {
+            if !board.valid_moves(disc).is_empty() {
+                let choice = players[disc as usize].select_move(&board, disc).unwrap();
+                assert!(board.is_valid_move(choice, disc));
+                board.apply_move(choice, disc).unwrap();
+            }
+            disc = disc.opposite();
+        }
+        assert_eq!(players[0].select_move(&board, Disc::Black), None);
+        assert_eq!(players[1].select_move(&board, Disc::White), None);
+    }
+
+    #[test]
+    fn alpha_beta_matches_minimax() {
+        let positions: [&[usize]; 4] = [&[], &[19, 18], &[19, 18, 17, 29, 37], &[19, 20, 21]];
+        for moves in positions {
+            let (board, disc) = play(moves);
+            for depth in 1..=4 {
+                let minimax = SearchPlayer::minimax(depth);
+                let alpha_beta = SearchPlayer::alpha_beta(depth);
+                let (_, expected) = minimax.search(&board, disc).unwrap();
+                let (_, value) = alpha_beta.search(&board, disc).unwrap();
+                assert_eq!(value, expected, "moves {:?}, depth {}", moves, depth);
+                if depth >= 3 {
+                    assert!(alpha_beta.nodes_searched() < minimax.nodes_searched());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corner_weighted_search_takes_corner() {
+        // black's corner capture at 63 flips as many discs as 39, 40, 47 and 55
+        let (board, disc) = play(&[
+            19, 34, 44, 37, 30, 52, 43, 38, 41, 33, 45, 48, 61, 23, 42, 46, 25, 17, 54, 62,
+        ]);
+        assert_eq!(disc, Disc::Black);
+        assert_eq!(SearchPlayer::minimax(1).best_move(&board, disc), Some(39));
+        let player = SearchPlayer::minimax(1).with_evaluator(CornerWeighted);
+        assert_eq!(player.best_move(&board, disc), Some(63));
+    }
+
+    #[test]
+    fn order_moves_puts_corners_first() {
+        let (board, disc) = play(&[
+            19, 34, 44, 37, 30, 52, 43, 38, 41, 33, 45, 48, 61, 23, 42, 46, 25, 17, 54, 62,
+        ]);
+        let mut moves = board.valid_moves(disc);
+        assert!(moves.contains(&63));
+        SearchPlayer::alpha_beta(1).order_moves(&board, disc, &mut moves);
+        assert_eq!(moves[0], 63);
+        let flips: Vec<usize> = moves[1..]
+            .iter()
+            .map(|&index| board.flip_count(index, disc))
+            .collect();
+        assert!(
+            flips.windows(2).all(|pair| pair[0] >= pair[1]),
+            "{:?}",
+            flips
+        );
+
+        // equal moves stay in index order
+        let board = StandardBoard::new();
+        let mut moves = board.valid_moves(Disc::Black);
+        SearchPlayer::alpha_beta(1).order_moves(&board, Disc::Black, &mut moves);
+        assert_eq!(&moves[..], &[19, 26, 37, 44]);
+    }
+
+    #[test]
+    fn time_budget_deepens_until_it_runs_out() {
+        let (board, disc) = play(&[19, 18, 17, 29, 37]);
+        // a budget far beyond what four plies need; the configured depth caps the deepening
+        let player = SearchPlayer::alpha_beta(4).with_time_budget(Duration::from_secs(60));
+        let choice = player.best_move(&board, disc);
+        assert_eq!(player.depth_reached(), 4);
+        assert_eq!(choice, SearchPlayer::alpha_beta(4).best_move(&board, disc));
+
+        let player = SearchPlayer::alpha_beta(4).with_time_budget(Duration::ZERO);
+        let choice = player.best_move(&board, disc);
+        assert_eq!(player.depth_reached(), 1);
+        assert_eq!(choice, SearchPlayer::alpha_beta(1).best_move(&board, disc));
+    }
+
+    #[test]
+    fn deadline_stops_search_inside_the_tree() {
+        let board = StandardBoard::new();
+        let player = SearchPlayer::minimax(6);
+        let past = Some(Instant::now());
+        let value = player.negamax(&board, Disc::Black, 6, -i32::MAX, i32::MAX, past);
+        assert_eq!(value, None);
+        assert_eq!(player.nodes_searched(), DEADLINE_CHECK_NODES);
+    }
+
+    #[test]
+    fn transposition_table_saves_nodes() {
+        let positions: [&[usize]; 3] = [&[], &[19, 18], &[19, 18, 17, 29, 37]];
+        for moves in positions {
+            let (board, disc) = play(moves);
+            for depth in 4..=6 {
+                let plain = SearchPlayer::alpha_beta(depth);
+                let cached = SearchPlayer::alpha_beta(depth).with_transposition_table();
+                let (_, expected) = plain.search(&board, disc).unwrap();
+                let (_, value) = cached.search(&board, disc).unwrap();
+                assert_eq!(value, expected, "moves {:?}, depth {}", moves, depth);
+            }
+            // transpositions need a few plies on each side to show up
+            let plain = SearchPlayer::alpha_beta(6);
+            let cached = SearchPlayer::alpha_beta(6).with_transposition_table();
+            plain.search(&board, disc);
+            cached.search(&board, disc);
+            assert!(
+                cached.nodes_searched() < plain.nodes_searched(),
+                "moves {:?}",
+                moves
+            );
+        }
+        let minimax = SearchPlayer::minimax(4);
+        let cached = SearchPlayer::minimax(4).with_transposition_table();
+        let board = StandardBoard::new();
+        assert_eq!(
+            cached.search(&board, Disc::Black),
+            minimax.search(&board, Disc::Black)
+        );
+    }
+}
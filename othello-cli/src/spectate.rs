@@ -0,0 +1,89 @@
+use othello_lib::board::StandardBoard;
+use othello_lib::game::{Game, GameEvent, GameOutcome};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// Plays `game` to the end, writing each move and the board after it to `out` and
+/// sleeping `delay` between turns. Returns the outcome once the game is over, or
+/// `None` if a player's choice was rejected and the game stopped short.
+pub fn spectate(
+    game: &mut Game,
+    delay: Duration,
+    out: &mut impl Write,
+) -> io::Result<Option<GameOutcome>> {
+    let mut board = game.board().clone();
+    let mut result = Ok(());
+    let outcome = game.run_with_observer(|event| {
+        if result.is_ok() {
+            result = show(event, &mut board, delay, out);
+        }
+    });
+    result.map(|_| outcome.ok())
+}
+
+fn show(
+    event: &GameEvent,
+    board: &mut StandardBoard,
+    delay: Duration,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match event {
+        GameEvent::MoveMade {
+            disc,
+            index,
+            flipped,
+        } => {
+            board
+                .apply_move(*index, *disc)
+                .expect("the game only reports legal moves");
+            let square = StandardBoard::algebraic_from_index(*index)
+                .expect("the game only reports moves on the board");
+            writeln!(
+                out,
+                "{:?} plays {} ({} flipped)",
+                disc,
+                square,
+                flipped.len()
+            )?;
+            writeln!(out, "{}", board)?;
+            thread::sleep(delay);
+        }
+        GameEvent::Passed(disc) => {
+            writeln!(out, "{:?} passes", disc)?;
+            thread::sleep(delay);
+        }
+        GameEvent::GameEnded(outcome) => match outcome {
+            Some(GameOutcome::Winner(disc)) => writeln!(out, "{:?} wins", disc)?,
+            Some(GameOutcome::Tie) => writeln!(out, "The game is a tie")?,
+            None => writeln!(out, "The game was not finished")?,
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer::ComputerPlayer;
+
+    #[test]
+    fn prints_every_move_and_the_result() {
+        let mut game = Game::new(Box::new(ComputerPlayer), Box::new(ComputerPlayer));
+        let mut out = Vec::new();
+        let outcome = spectate(&mut game, Duration::ZERO, &mut out).unwrap();
+        assert!(outcome.is_some());
+
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Black plays d3 (1 flipped)"));
+        let moves = text.lines().filter(|line| line.contains(" plays ")).count();
+        let passes = text
+            .lines()
+            .filter(|line| line.ends_with(" passes"))
+            .count();
+        assert_eq!(moves + passes, game.history().len());
+        assert!(text.contains(&game.board().to_string()));
+        assert!(text.ends_with("wins\n") || text.ends_with("tie\n"));
+    }
+}
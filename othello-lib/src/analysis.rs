@@ -0,0 +1,260 @@
+use crate::board::Board;
+use crate::disc::Disc;
+#[cfg(feature = "std")]
+use crate::game::{Game, GameOutcome};
+#[cfg(feature = "std")]
+use crate::player::Player;
+
+/// Counts the positions reached after `depth` plies with `disc` to move, for checking
+/// move generation against known totals.
+///
+/// A forced pass counts as a ply of its own, so it uses up one level of depth. A
+/// finished game is a leaf however much depth is left.
+pub fn perft(board: &Board, disc: Disc, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = board.valid_moves(disc);
+    if moves.is_empty() {
+        if board.valid_moves(disc.opposite()).is_empty() {
+            return 1;
+        }
+        return perft(board, disc.opposite(), depth - 1);
+    }
+    moves
+        .into_iter()
+        .map(|index| perft(&after(board, index, disc), disc.opposite(), depth - 1))
+        .sum()
+}
+
+/// Results of `play_match`, counted per game.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MatchStats {
+    pub black_wins: u32,
+    pub white_wins: u32,
+    pub ties: u32,
+}
+
+/// Plays `games` games from the standard opening between `black` and `white` and
+/// tallies the results. Each game is a fresh `Game` played out with `Game::run`, so
+/// its rules apply, including time limits. A player whose choice `run` rejects
+/// loses that game. The players are borrowed by every game, and a game may ask a
+/// time-limited player on another thread, so they must be `Sync`.
+#[cfg(feature = "std")]
+pub fn play_match(
+    black: &(dyn Player + Sync),
+    white: &(dyn Player + Sync),
+    games: u32,
+) -> MatchStats {
+    let mut stats = MatchStats::default();
+    for _ in 0..games {
+        let mut game = Game::new(Box::new(black), Box::new(white));
+        let outcome = game
+            .run()
+            .unwrap_or_else(|_| GameOutcome::Winner(game.current_disc().opposite()));
+        match outcome {
+            GameOutcome::Winner(Disc::Black) => stats.black_wins += 1,
+            GameOutcome::Winner(Disc::White) => stats.white_wins += 1,
+            GameOutcome::Tie => stats.ties += 1,
+        }
+    }
+    stats
+}
+
+/// Most empty squares `solve_endgame` will take on; beyond this the full search is too slow.
+pub const ENDGAME_EMPTIES: usize = 10;
+
+/// Plays the endgame out perfectly, returning `disc`'s best move and the final disc
+/// count difference from `disc`'s point of view when both sides play best.
+///
+/// The move is `None` when `disc` has to pass or the game is over; the value then
+/// still accounts for the rest of the game. Among moves of equal value the lowest
+/// index is chosen. Positions with more than `ENDGAME_EMPTIES` empty squares are not
+/// searched and give `None`.
+pub fn solve_endgame(board: &Board, disc: Disc) -> Option<(Option<usize>, i32)> {
+    if board.empty_count() > ENDGAME_EMPTIES {
+        return None;
+    }
+    let mut best: (Option<usize>, i32) = (None, -i32::MAX);
+    for index in board.valid_moves(disc) {
+        let next = after(board, index, disc);
+        let value = -negamax(&next, disc.opposite(), -i32::MAX, -best.1);
+        if best.0.is_none() || value > best.1 {
+            best = (Some(index), value);
+        }
+    }
+    if best.0.is_none() {
+        return Some((None, negamax(board, disc, -i32::MAX, i32::MAX)));
+    }
+    Some(best)
+}
+
+/// Exact value of the position for `disc`, searched to the end of the game.
+fn negamax(board: &Board, disc: Disc, mut alpha: i32, beta: i32) -> i32 {
+    let moves = board.valid_moves(disc);
+    if moves.is_empty() {
+        if board.valid_moves(disc.opposite()).is_empty() {
+            return board.count_discs(disc) as i32 - board.count_discs(disc.opposite()) as i32;
+        }
+        return -negamax(board, disc.opposite(), -beta, -alpha);
+    }
+    let mut best = -i32::MAX;
+    for index in moves {
+        let value = -negamax(&after(board, index, disc), disc.opposite(), -beta, -alpha);
+        best = best.max(value);
+        alpha = alpha.max(value);
+        if alpha >= beta {
+            break;
+        }
+    }
+    best
+}
+
+fn after(board: &Board, index: usize, disc: Disc) -> Board {
+    let mut next = board.clone();
+    next.apply_move(index, disc)
+        .expect("valid moves can always be applied");
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::StandardBoard;
+
+    #[test]
+    fn perft_from_opening() {
+        let board = StandardBoard::new();
+        let expected = [1, 4, 12, 56, 244, 1396, 8200];
+        for (depth, &count) in expected.iter().enumerate() {
+            assert_eq!(
+                perft(&board, Disc::Black, depth as u32),
+                count,
+                "depth {}",
+                depth
+            );
+        }
+    }
+
+    /// Only a1 and h8 are empty; black can take either corner, white neither.
+    fn two_corners_left() -> StandardBoard {
+        let placements: Vec<(usize, Disc)> = (1..63)
+            .map(|index| match index {
+                1 | 62 => (index, Disc::White),
+                _ => (index, Disc::Black),
+            })
+            .collect();
+        StandardBoard::set_position(&placements).unwrap()
+    }
+
+    #[test]
+    fn perft_counts_passes_as_plies() {
+        let board = two_corners_left();
+        assert_eq!(perft(&board, Disc::Black, 1), 2);
+        // white's pass is the only move at the first ply
+        assert_eq!(perft(&board, Disc::White, 1), 1);
+        assert_eq!(perft(&board, Disc::White, 2), 2);
+        assert_eq!(perft(&board, Disc::White, 3), 2);
+        // both corners are gone after four plies and the finished games stay leaves
+        assert_eq!(perft(&board, Disc::White, 4), 2);
+        assert_eq!(perft(&board, Disc::White, 10), 2);
+    }
+
+    /// The game value without pruning, to check the solver against.
+    fn exhaustive(board: &Board, disc: Disc) -> i32 {
+        let moves = board.valid_moves(disc);
+        if moves.is_empty() {
+            if board.valid_moves(disc.opposite()).is_empty() {
+                return board.count_discs(disc) as i32 - board.count_discs(disc.opposite()) as i32;
+            }
+            return -exhaustive(board, disc.opposite());
+        }
+        moves
+            .into_iter()
+            .map(|index| -exhaustive(&after(board, index, disc), disc.opposite()))
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn solve_endgame_two_corners() {
+        // black takes both corners
+        let board = two_corners_left();
+        assert_eq!(solve_endgame(&board, Disc::Black), Some((Some(0), 64)));
+        assert_eq!(solve_endgame(&board, Disc::White), Some((None, -64)));
+    }
+
+    #[test]
+    fn solve_endgame_finds_the_only_win() {
+        // white to move with h2, g3 and b7 empty; b7 wins by 8, g3 loses by 10
+        let (board, disc) = StandardBoard::from_compact(
+            "WWWWWWWWWWWWWBW-WWBWBW-BWWWBWWBBWWWWBBBBBBBBBBBBB-WWWWBBBBBBBBBB w",
+        )
+        .unwrap();
+        assert_eq!(disc, Disc::White);
+        assert_eq!(&board.valid_moves(Disc::White)[..], &[22, 49]);
+        assert_eq!(solve_endgame(&board, Disc::White), Some((Some(49), 8)));
+        assert_eq!(exhaustive(&board, Disc::White), 8);
+        let after_g3 = after(&board, 22, Disc::White);
+        assert_eq!(-exhaustive(&after_g3, Disc::Black), -10);
+    }
+
+    #[test]
+    fn solve_endgame_skips_large_positions() {
+        assert_eq!(solve_endgame(&StandardBoard::new(), Disc::Black), None);
+    }
+
+    #[cfg(feature = "std")]
+    struct FirstMove;
+    #[cfg(feature = "std")]
+    impl Player for FirstMove {
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            board.valid_moves(disc).first().copied()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct LastMove;
+    #[cfg(feature = "std")]
+    impl Player for LastMove {
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            board.valid_moves(disc).last().copied()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct Illegal;
+    #[cfg(feature = "std")]
+    impl Player for Illegal {
+        fn select_move(&self, _: &Board, _: Disc) -> Option<usize> {
+            Some(0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn play_match_counts_results() {
+        let black_sweep = MatchStats {
+            black_wins: 5,
+            ..MatchStats::default()
+        };
+        // fixed strategies replay the same game every time; black wins it either way round
+        assert_eq!(play_match(&FirstMove, &LastMove, 5), black_sweep);
+        assert_eq!(play_match(&LastMove, &FirstMove, 5), black_sweep);
+        assert_eq!(play_match(&FirstMove, &LastMove, 0), MatchStats::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn play_match_illegal_move_loses() {
+        let stats = play_match(&Illegal, &FirstMove, 3);
+        assert_eq!(
+            stats,
+            MatchStats {
+                white_wins: 3,
+                ..MatchStats::default()
+            }
+        );
+    }
+}
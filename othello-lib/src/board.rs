@@ -1,16 +1,79 @@
 use crate::disc::Disc;
 use arrayvec::ArrayVec;
-use std::fmt::{self, Debug};
+use core::fmt::{self, Debug};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BoardError {
     OutOfBounds,
     SquareOccupied,
     InvalidMove,
+    ParseError,
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoardError::OutOfBounds => "square is off the board",
+            BoardError::SquareOccupied => "square already occupied",
+            BoardError::InvalidMove => "move is not legal",
+            BoardError::ParseError => "could not parse board notation",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoardError {}
+
+/// Disc counts along one edge of the board.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EdgeControl {
+    pub black: usize,
+    pub white: usize,
+    pub empty: usize,
+    /// The color holding a majority of the edge while the other has no discs on it.
+    pub dominant: Option<Disc>,
+}
+
+/// Whether an even or odd number of squares is left empty.
+/// With no passes, an odd count means the side to move gets the last move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+/// How the four center discs are arranged at the start of a game.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OpeningStyle {
+    /// White on the top-left to bottom-right diagonal, Black on the other.
+    #[default]
+    Standard,
+    /// The colors of the two diagonals swapped.
+    Reversed,
 }
 
-#[derive(Copy, Clone)]
-enum Direction {
+/// What a move does to one square, as shown by `StandardBoard::move_overlay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveMark {
+    /// The disc is placed here.
+    Placed,
+    /// An opposing disc here is flipped.
+    Flipped,
+    /// The square is left as it is.
+    None,
+}
+
+/// One of the eight compass directions a line of discs can run in, with north
+/// towards row 0 and west towards column 0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
     North,
     NorthEast,
     East,
@@ -21,7 +84,8 @@ enum Direction {
     NorthWest,
 }
 impl Direction {
-    fn delta_row_col(self) -> (isize, isize) {
+    /// The change in row and column for one step in this direction.
+    pub fn delta_row_col(self) -> (isize, isize) {
         match self {
             Self::North => (-1, 0),
             Self::NorthEast => (-1, 1),
@@ -33,7 +97,28 @@ impl Direction {
             Self::NorthWest => (-1, -1),
         }
     }
-    const ALL: [Direction; 8] = [
+    fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::NorthEast => Self::SouthWest,
+            Self::East => Self::West,
+            Self::SouthEast => Self::NorthWest,
+            Self::South => Self::North,
+            Self::SouthWest => Self::NorthEast,
+            Self::West => Self::East,
+            Self::NorthWest => Self::SouthEast,
+        }
+    }
+    /// Every direction, clockwise from north.
+    ///
+    /// ```
+    /// use othello_lib::board::Direction;
+    ///
+    /// assert_eq!(Direction::ALL.len(), 8);
+    /// assert_eq!(Direction::ALL[0], Direction::North);
+    /// assert_eq!(Direction::ALL[2].delta_row_col(), (0, 1));
+    /// ```
+    pub const ALL: [Direction; 8] = [
         Self::North,
         Self::NorthEast,
         Self::East,
@@ -45,22 +130,100 @@ impl Direction {
     ];
 }
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct Board {
-    squares: [Option<Disc>; Board::BOARD_SURFACE],
+/// Largest supported board side. Move lists are sized for this bound, since
+/// stable Rust can't use `N * N` as an array length in generic code.
+pub const MAX_SIZE: usize = 10;
+pub const MAX_SURFACE: usize = MAX_SIZE * MAX_SIZE;
+
+/// The classic square weights for an 8x8 board, in index order. Corners are worth
+/// 100 since they can never be flipped. The X-squares diagonal to a corner (-50)
+/// and the C-squares beside it (-20) usually give the corner away. Edges are mildly
+/// positive and the interior close to neutral.
+#[rustfmt::skip]
+pub const POSITION_WEIGHTS: [i32; 64] = [
+    100, -20,  10,   5,   5,  10, -20, 100,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+     10,  -2,  -1,  -1,  -1,  -1,  -2,  10,
+      5,  -2,  -1,  -1,  -1,  -1,  -2,   5,
+      5,  -2,  -1,  -1,  -1,  -1,  -2,   5,
+     10,  -2,  -1,  -1,  -1,  -1,  -2,  10,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+    100, -20,  10,   5,   5,  10, -20, 100,
+];
+
+const fn zobrist_keys() -> [[u64; 2]; MAX_SURFACE] {
+    // splitmix64 from a fixed seed, so hashes are stable across runs
+    let mut keys = [[0; 2]; MAX_SURFACE];
+    let mut state: u64 = 0x0123_4567_89ab_cdef;
+    let mut i = 0;
+    while i < MAX_SURFACE * 2 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        keys[i / 2][i % 2] = z ^ (z >> 31);
+        i += 1;
+    }
+    keys
 }
 
-impl Board {
-    pub const BOARD_WIDTH: usize = 8;
-    pub const BOARD_HEIGHT: usize = 8;
-    pub const BOARD_MAX_DIM: usize = 8; // should be equal to the max of WIDTH and HEIGHT
-    pub const BOARD_SURFACE: usize = Board::BOARD_WIDTH * Board::BOARD_HEIGHT;
+static ZOBRIST_KEYS: [[u64; 2]; MAX_SURFACE] = zobrist_keys();
 
-    pub fn new() -> Self {
-        let mut board = Self {
-            squares: [None; Self::BOARD_SURFACE],
+/// A square Othello board with `N` rows and columns, `N` even.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Board<const N: usize = 8> {
+    /// One bitboard per color, indexed by `Disc as usize`; bit `i` is square `i`.
+    discs: [u128; 2],
+    hash: u64,
+}
+
+pub type StandardBoard = Board<8>;
+
+impl<const N: usize> Board<N> {
+    pub const BOARD_WIDTH: usize = N;
+    pub const BOARD_HEIGHT: usize = N;
+    pub const BOARD_MAX_DIM: usize = N;
+    pub const BOARD_SURFACE: usize = N * N;
+
+    const ALL_SQUARES: u128 = (1 << (N * N)) - 1;
+    const FIRST_COLUMN: u128 = Self::column_mask(0);
+    const LAST_COLUMN: u128 = Self::column_mask(N - 1);
+
+    const fn column_mask(col: usize) -> u128 {
+        let mut mask = 0;
+        let mut row = 0;
+        while row < N {
+            mask |= 1 << (N * row + col);
+            row += 1;
+        }
+        mask
+    }
+
+    /// A board with no discs at all. Unlike `new`, the four center discs of the
+    /// opening are not placed.
+    pub fn empty() -> Self {
+        const {
+            assert!(
+                N >= 4 && N.is_multiple_of(2) && N <= MAX_SIZE,
+                "board size must be even and between 4 and MAX_SIZE"
+            )
         };
 
+        Self {
+            discs: [0; 2],
+            hash: 0,
+        }
+    }
+
+    /// The standard opening: two discs of each color crossed in the center.
+    pub fn new() -> Self {
+        Self::with_opening(OpeningStyle::Standard)
+    }
+
+    /// The opening with the center discs arranged as `style` says.
+    pub fn with_opening(style: OpeningStyle) -> Self {
+        let mut board = Self::empty();
+
         let mid_row = Self::BOARD_HEIGHT / 2;
         let mid_col = Self::BOARD_WIDTH / 2;
 
@@ -72,6 +235,10 @@ impl Board {
         ];
 
         for &(r, c, disc) in &init {
+            let disc = match style {
+                OpeningStyle::Standard => disc,
+                OpeningStyle::Reversed => disc.opposite(),
+            };
             let idx = board.index(r, c).expect("center coords should be valid");
             board
                 .set_field(idx, disc)
@@ -81,19 +248,70 @@ impl Board {
         board
     }
 
+    /// A board holding exactly `placements`, with every other square empty.
+    /// Fails with `OutOfBounds` for an index off the board and `SquareOccupied`
+    /// when an index is listed twice.
+    pub fn set_position(placements: &[(usize, Disc)]) -> Result<Self, BoardError> {
+        let mut board = Self::empty();
+        for &(index, disc) in placements {
+            if board.get_field(index)?.is_some() {
+                return Err(BoardError::SquareOccupied);
+            }
+            board.set_field(index, disc)?;
+        }
+        Ok(board)
+    }
+
+    /// A board laid out row by row from the top, handy for writing out test positions.
+    pub fn from_rows(rows: [[Option<Disc>; N]; N]) -> Self {
+        Self::from_fn(|row, col| rows[row][col])
+    }
+
     pub fn index(&self, row: usize, col: usize) -> Result<usize, BoardError> {
-        match (row, col) {
-            (Board::BOARD_HEIGHT.., _) => Err(BoardError::OutOfBounds),
-            (_, Board::BOARD_WIDTH..) => Err(BoardError::OutOfBounds),
-            (row, col) => Ok(Board::BOARD_WIDTH * row + col),
+        if row >= Self::BOARD_HEIGHT || col >= Self::BOARD_WIDTH {
+            Err(BoardError::OutOfBounds)
+        } else {
+            Ok(Self::BOARD_WIDTH * row + col)
         }
     }
 
     pub fn row_col(&self, index: usize) -> Result<(usize, usize), BoardError> {
-        match index {
-            Board::BOARD_SURFACE.. => Err(BoardError::OutOfBounds),
-            index => Ok((index / Board::BOARD_WIDTH, index % Board::BOARD_WIDTH)),
+        if index >= Self::BOARD_SURFACE {
+            Err(BoardError::OutOfBounds)
+        } else {
+            Ok((index / Self::BOARD_WIDTH, index % Self::BOARD_WIDTH))
+        }
+    }
+
+    /// Parses standard notation such as `"d3"`: a column letter from `a` followed by a
+    /// row number from `1`, with `a1` the top-left square. Letters are case-insensitive.
+    pub fn index_from_algebraic(notation: &str) -> Result<usize, BoardError> {
+        let mut chars = notation.chars();
+        let file = chars
+            .next()
+            .filter(char::is_ascii_alphabetic)
+            .ok_or(BoardError::ParseError)?
+            .to_ascii_lowercase();
+        let rank = chars.as_str();
+        if rank.is_empty() || rank.starts_with('0') || !rank.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(BoardError::ParseError);
         }
+        let row = rank.parse::<usize>().map_err(|_| BoardError::OutOfBounds)? - 1;
+        let col = (file as u8 - b'a') as usize;
+        if row >= Self::BOARD_HEIGHT || col >= Self::BOARD_WIDTH {
+            return Err(BoardError::OutOfBounds);
+        }
+        Ok(Self::BOARD_WIDTH * row + col)
+    }
+
+    /// The standard notation for `index`, e.g. `"d3"` for 19 on an 8x8 board.
+    #[cfg(feature = "alloc")]
+    pub fn algebraic_from_index(index: usize) -> Result<String, BoardError> {
+        if index >= Self::BOARD_SURFACE {
+            return Err(BoardError::OutOfBounds);
+        }
+        let (row, col) = (index / Self::BOARD_WIDTH, index % Self::BOARD_WIDTH);
+        Ok(format!("{}{}", (b'a' + col as u8) as char, row + 1))
     }
 
     fn step_row(&self, row: usize, delta: isize) -> Option<usize> {
@@ -125,62 +343,221 @@ impl Board {
     }
 
     pub fn get_field(&self, index: usize) -> Result<Option<Disc>, BoardError> {
-        self.squares
-            .get(index)
-            .copied()
-            .ok_or(BoardError::OutOfBounds)
+        if index >= Self::BOARD_SURFACE {
+            return Err(BoardError::OutOfBounds);
+        }
+        Ok(self.square(index))
     }
 
-    fn set_field(&mut self, index: usize, disc: Disc) -> Result<(), BoardError> {
-        let square: &mut Option<Disc> =
-            self.squares.get_mut(index).ok_or(BoardError::OutOfBounds)?;
-        *square = Some(disc);
-        Ok(())
+    /// Same as `get_field`, addressed by row and column.
+    pub fn owner_at(&self, row: usize, col: usize) -> Result<Option<Disc>, BoardError> {
+        self.get_field(self.index(row, col)?)
+    }
+
+    fn square(&self, index: usize) -> Option<Disc> {
+        let bit = 1 << index;
+        if self.discs[Disc::Black as usize] & bit != 0 {
+            Some(Disc::Black)
+        } else if self.discs[Disc::White as usize] & bit != 0 {
+            Some(Disc::White)
+        } else {
+            None
+        }
+    }
+
+    fn row(&self, row: usize) -> impl Iterator<Item = Option<Disc>> + '_ {
+        (N * row..N * (row + 1)).map(|index| self.square(index))
+    }
+
+    /// The squares of `row`, from column 0 across.
+    pub fn rank(&self, row: usize) -> Result<[Option<Disc>; N], BoardError> {
+        if row >= N {
+            return Err(BoardError::OutOfBounds);
+        }
+        Ok(core::array::from_fn(|col| self.square(N * row + col)))
+    }
+
+    /// The squares of `col`, from row 0 down.
+    pub fn file(&self, col: usize) -> Result<[Option<Disc>; N], BoardError> {
+        if col >= N {
+            return Err(BoardError::OutOfBounds);
+        }
+        Ok(core::array::from_fn(|row| self.square(N * row + col)))
+    }
+
+    fn squares(&self) -> impl Iterator<Item = Option<Disc>> + '_ {
+        (0..Self::BOARD_SURFACE).map(|index| self.square(index))
+    }
+
+    /// The squares touching `index` and what is on them, in `Direction` order.
+    pub fn neighbors(&self, index: usize) -> ArrayVec<(usize, Option<Disc>), 8> {
+        Direction::ALL
+            .iter()
+            .filter_map(|&dir| self.next_index(index, dir))
+            .map(|next| (next, self.square(next)))
+            .collect()
     }
 
-    fn flips_in_direction(
+    /// The squares met walking from `start` in `dir`, not including `start`, up to the edge.
+    pub fn line_iter(
         &self,
         start: usize,
-        disc: Disc,
         dir: Direction,
-    ) -> Option<ArrayVec<usize, { Board::BOARD_MAX_DIM }>> {
+    ) -> impl Iterator<Item = (usize, Option<Disc>)> + '_ {
+        core::iter::successors(self.next_index(start, dir), move |&index| {
+            self.next_index(index, dir)
+        })
+        .map(|index| (index, self.square(index)))
+    }
+
+    /// Every square whose contents differ between `self` and `other`, in index order,
+    /// as `(index, before, after)` with `self` as the before.
+    #[cfg(feature = "alloc")]
+    pub fn diff(&self, other: &Self) -> Vec<(usize, Option<Disc>, Option<Disc>)> {
+        let changed = (self.discs[0] ^ other.discs[0]) | (self.discs[1] ^ other.discs[1]);
+        bit_indices(changed)
+            .map(|index| (index, self.square(index), other.square(index)))
+            .collect()
+    }
+
+    /// Every square with its index, in index order.
+    pub fn iter_squares(&self) -> impl Iterator<Item = (usize, Option<Disc>)> + '_ {
+        self.squares().enumerate()
+    }
+
+    fn set_field(&mut self, index: usize, disc: Disc) -> Result<(), BoardError> {
+        if index >= Self::BOARD_SURFACE {
+            return Err(BoardError::OutOfBounds);
+        }
+        let bit = 1 << index;
+        if self.discs[disc as usize] & bit != 0 {
+            return Ok(());
+        }
         let opponent = disc.opposite();
-        let mut flips = ArrayVec::<usize, { Board::BOARD_MAX_DIM }>::new();
-        let mut index = self.next_index(start, dir)?;
-        if self.get_field(index).ok()? != Some(opponent) {
-            return None;
+        if self.discs[opponent as usize] & bit != 0 {
+            self.discs[opponent as usize] ^= bit;
+            self.hash ^= Self::zobrist_key(index, opponent);
         }
-        flips.push(index);
-        while let Some(next) = self.next_index(index, dir) {
-            index = next;
-            match self.get_field(index).ok()? {
-                Some(d) if d == opponent => flips.push(index),
-                Some(d) if d == disc => return Some(flips),
-                _ => return None,
-            }
+        self.discs[disc as usize] |= bit;
+        self.hash ^= Self::zobrist_key(index, disc);
+        Ok(())
+    }
+
+    fn clear_field(&mut self, index: usize) {
+        if let Some(old) = self.square(index) {
+            self.discs[old as usize] ^= 1 << index;
+            self.hash ^= Self::zobrist_key(index, old);
         }
-        None
     }
 
-    fn all_flips(
-        &self,
-        start: usize,
-        disc: Disc,
-    ) -> Option<ArrayVec<usize, { Board::BOARD_SURFACE }>> {
-        let mut all = ArrayVec::<usize, { Board::BOARD_SURFACE }>::new();
+    fn empty_mask(&self) -> u128 {
+        !(self.discs[0] | self.discs[1]) & Self::ALL_SQUARES
+    }
+
+    /// Moves every bit of `mask` one square in `dir`, dropping bits that leave the board.
+    fn shift(mask: u128, dir: Direction) -> u128 {
+        let (dr, dc) = dir.delta_row_col();
+        let offset = dr * N as isize + dc;
+        let shifted = if offset >= 0 {
+            mask << offset
+        } else {
+            mask >> -offset
+        };
+        // a step east or west that wraps lands in the opposite edge column
+        let wrapped = match dc {
+            1 => Self::FIRST_COLUMN,
+            -1 => Self::LAST_COLUMN,
+            _ => 0,
+        };
+        shifted & !wrapped & Self::ALL_SQUARES
+    }
+
+    /// The random key XORed into the hash while `disc` occupies `index`.
+    fn zobrist_key(index: usize, disc: Disc) -> u64 {
+        match disc {
+            Disc::Black => ZOBRIST_KEYS[index][0],
+            Disc::White => ZOBRIST_KEYS[index][1],
+        }
+    }
+
+    /// Zobrist hash of the position, maintained incrementally as discs are placed and flipped.
+    pub fn zobrist_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Mask of the discs flipped by `disc` playing on `start`, zero if none would be.
+    fn flip_mask(&self, start: usize, disc: Disc) -> u128 {
+        let own = self.discs[disc as usize];
+        let opponent = self.discs[disc.opposite() as usize];
+        let mut flips = 0;
         for &dir in Direction::ALL.iter() {
-            if let Some(flips) = self.flips_in_direction(start, disc, dir) {
-                all.try_extend_from_slice(&flips).ok()?;
+            let mut line = 0;
+            let mut cursor = Self::shift(1 << start, dir);
+            while cursor & opponent != 0 {
+                line |= cursor;
+                cursor = Self::shift(cursor, dir);
+            }
+            if cursor & own != 0 {
+                flips |= line;
             }
         }
-        if all.is_empty() {
-            None
-        } else {
-            Some(all)
+        flips
+    }
+
+    fn all_flips(&self, start: usize, disc: Disc) -> Option<ArrayVec<usize, MAX_SURFACE>> {
+        match self.flip_mask(start, disc) {
+            0 => None,
+            flips => Some(bit_indices(flips).collect()),
+        }
+    }
+
+    /// The discs `disc` would flip by playing on `start`, in index order, or `None` if
+    /// the move is illegal. `start` itself is never included, since it is placed, not flipped.
+    #[cfg(feature = "alloc")]
+    pub fn flips_for(&self, start: usize, disc: Disc) -> Option<Vec<usize>> {
+        let Ok(None) = self.get_field(start) else {
+            return None;
+        };
+        self.all_flips(start, disc).map(|flips| flips.to_vec())
+    }
+
+    /// The discs `disc` would flip in `dir` alone by playing on `start`, nearest first.
+    /// Returns `None` when nothing flips in that direction, including when `start`
+    /// is occupied or off the board.
+    #[cfg(feature = "alloc")]
+    pub fn flips_along(&self, start: usize, disc: Disc, dir: Direction) -> Option<Vec<usize>> {
+        let Ok(None) = self.get_field(start) else {
+            return None;
+        };
+        let mut flips = Vec::new();
+        for (index, square) in self.line_iter(start, dir) {
+            match square {
+                Some(owner) if owner == disc => return (!flips.is_empty()).then_some(flips),
+                Some(_) => flips.push(index),
+                None => return None,
+            }
         }
+        None
     }
 
     pub fn apply_move(&mut self, start: usize, disc: Disc) -> Result<(), BoardError> {
+        self.apply_move_returning_flips(start, disc).map(|_| ())
+    }
+
+    /// Applies `moves` in order. On the first illegal move, stops and returns its
+    /// position in `moves` with the error; the moves before it stay applied.
+    pub fn apply_sequence(&mut self, moves: &[(usize, Disc)]) -> Result<(), (usize, BoardError)> {
+        for (step, &(start, disc)) in moves.iter().enumerate() {
+            self.apply_move(start, disc).map_err(|err| (step, err))?;
+        }
+        Ok(())
+    }
+
+    pub fn apply_move_returning_flips(
+        &mut self,
+        start: usize,
+        disc: Disc,
+    ) -> Result<ArrayVec<usize, MAX_SURFACE>, BoardError> {
         match self.get_field(start) {
             Ok(None) => {}
             Ok(_) => return Err(BoardError::SquareOccupied),
@@ -188,9 +565,62 @@ impl Board {
         }
         let flips = self.all_flips(start, disc).ok_or(BoardError::InvalidMove)?;
         self.set_field(start, disc)?;
-        for index in flips {
+        for &index in &flips {
             self.set_field(index, disc)?
         }
+        Ok(flips)
+    }
+
+    /// Places `disc` on `start` and turns over `flips`, without checking either, for
+    /// callers that already know the move is legal, such as a search replaying flip
+    /// lists it computed earlier. `flips` must be exactly what `disc` playing on the
+    /// empty square `start` turns over; anything else leaves an unreachable position.
+    /// Panics if an index is off the board.
+    pub fn apply_move_unchecked(&mut self, start: usize, disc: Disc, flips: &[usize]) {
+        for &index in flips.iter().chain([&start]) {
+            self.set_field(index, disc)
+                .expect("indices passed to apply_move_unchecked are on the board");
+        }
+    }
+
+    /// Plays `disc` on `start` and returns the number of discs flipped. Legality is
+    /// checked and the flips applied from a single mask, so nothing is collected, and
+    /// the board is left untouched when the move fails.
+    pub fn try_apply(&mut self, start: usize, disc: Disc) -> Result<u32, BoardError> {
+        if self.get_field(start)?.is_some() {
+            return Err(BoardError::SquareOccupied);
+        }
+        let flips = self.flip_mask(start, disc);
+        if flips == 0 {
+            return Err(BoardError::InvalidMove);
+        }
+        let opponent = disc.opposite();
+        self.discs[disc as usize] |= flips | 1 << start;
+        self.discs[opponent as usize] ^= flips;
+        self.hash ^= Self::zobrist_key(start, disc);
+        for index in bit_indices(flips) {
+            self.hash ^= Self::zobrist_key(index, disc) ^ Self::zobrist_key(index, opponent);
+        }
+        Ok(flips.count_ones())
+    }
+
+    /// Reverses `apply_move_returning_flips`: empties `start` and turns `flips` back
+    /// to the opponent. Fails without changing anything unless `disc` holds all of them.
+    pub fn undo_move(
+        &mut self,
+        start: usize,
+        disc: Disc,
+        flips: &[usize],
+    ) -> Result<(), BoardError> {
+        for &index in flips.iter().chain([&start]) {
+            if self.get_field(index)? != Some(disc) {
+                return Err(BoardError::InvalidMove);
+            }
+        }
+        self.clear_field(start);
+        for &index in flips {
+            self.set_field(index, disc.opposite())?;
+        }
         Ok(())
     }
 
@@ -198,167 +628,665 @@ impl Board {
         let Ok(None) = self.get_field(start) else {
             return false;
         };
-        self.all_flips(start, disc).is_some()
+        self.flip_mask(start, disc) != 0
+    }
+
+    /// Number of discs `disc` would flip by playing on `start`; zero if the move is illegal.
+    /// Cheaper than `all_flips` since no indices are collected.
+    pub fn flip_count(&self, start: usize, disc: Disc) -> usize {
+        let Ok(None) = self.get_field(start) else {
+            return 0;
+        };
+        self.flip_mask(start, disc).count_ones() as usize
     }
 
+    /// Number of `disc`'s discs, as a population count of its bitboard. The board keeps
+    /// no separate disc counters: the count already costs one instruction, and cached
+    /// counters would have to be kept in step by every mutation path.
     pub fn count_discs(&self, disc: Disc) -> usize {
-        self.squares
-            .iter()
-            .copied()
-            .filter(|&s| s == Some(disc))
-            .count()
+        self.discs[disc as usize].count_ones() as usize
     }
 
     pub fn count_empty_squares(&self) -> usize {
-        self.squares.iter().copied().filter(|&s| s == None).count()
+        self.empty_mask().count_ones() as usize
     }
 
-    pub fn valid_moves(&self, disc: Disc) -> ArrayVec<usize, { Board::BOARD_SURFACE }> {
-        self.squares
-            .iter()
-            .enumerate()
-            .filter_map(|(i, s)| s.is_none().then_some(i))
-            .filter(|&i| self.is_valid_move(i, disc))
-            .collect()
+    /// Disc and empty square counts together, as `(black, white, empty)`.
+    pub fn disc_counts(&self) -> (usize, usize, usize) {
+        let black = self.count_discs(Disc::Black);
+        let white = self.count_discs(Disc::White);
+        (black, white, N * N - black - white)
     }
-}
 
-impl fmt::Display for Board {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in 0..Board::BOARD_HEIGHT {
-            for col in 0..Board::BOARD_WIDTH {
-                let sym = match self.squares[row * Board::BOARD_WIDTH + col] {
-                    Some(Disc::Black) => '○',
-                    Some(Disc::White) => '●',
-                    None => '.',
-                };
-                write!(f, "{} ", sym)?;
-            }
-            writeln!(f)?;
+    /// Same as `count_empty_squares`.
+    pub fn empty_count(&self) -> usize {
+        self.count_empty_squares()
+    }
+
+    /// Parity of the total number of empty squares.
+    pub fn empty_parity(&self) -> Parity {
+        match self.count_empty_squares() % 2 {
+            0 => Parity::Even,
+            _ => Parity::Odd,
         }
-        Ok(())
     }
-}
 
-impl fmt::Debug for Board {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in 0..Board::BOARD_HEIGHT {
-            for col in 0..Board::BOARD_WIDTH {
-                let sym = match self.squares[row * Board::BOARD_WIDTH + col] {
-                    Some(Disc::Black) => '○',
-                    Some(Disc::White) => '●',
-                    None => '.',
-                };
-                write!(f, "{} ", sym)?;
+    /// The empty squares split into connected regions, for parity by region. Squares
+    /// are connected when they share a side (4-connected), so empties touching only at
+    /// a corner are separate regions. Regions are ordered by their lowest square, and
+    /// each lists its squares in ascending order.
+    #[cfg(feature = "alloc")]
+    pub fn empty_regions(&self) -> Vec<Vec<usize>> {
+        let sides = [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+        let mut unvisited = self.empty_mask();
+        let mut regions = Vec::new();
+        while unvisited != 0 {
+            let mut region = unvisited & unvisited.wrapping_neg();
+            loop {
+                let grown = sides
+                    .iter()
+                    .fold(region, |acc, &dir| acc | Self::shift(region, dir))
+                    & unvisited;
+                if grown == region {
+                    break;
+                }
+                region = grown;
             }
-            writeln!(f)?;
+            unvisited &= !region;
+            regions.push(bit_indices(region).collect());
         }
-        Ok(())
+        regions
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    pub fn is_full(&self) -> bool {
+        self.empty_mask() == 0
+    }
 
-    #[test]
-    fn index_valid_coordinates() {
-        let board = Board::new();
-        assert_eq!(board.index(0, 0), Ok(0));
-        assert_eq!(board.index(0, 7), Ok(7));
-        assert_eq!(board.index(7, 0), Ok(56));
-        assert_eq!(board.index(7, 7), Ok(63))
+    /// Final scores as `(black, white)` under tournament rules. Once neither side can
+    /// move, the empty squares go to whoever has more discs, or are split evenly on a
+    /// tie (an even board always leaves an even number of empties then). While moves
+    /// remain this is just the disc count.
+    pub fn tournament_score(&self) -> (usize, usize) {
+        let (black, white, empty) = self.disc_counts();
+        if self.any_moves() {
+            return (black, white);
+        }
+        match black.cmp(&white) {
+            core::cmp::Ordering::Greater => (black + empty, white),
+            core::cmp::Ordering::Less => (black, white + empty),
+            core::cmp::Ordering::Equal => (black + empty / 2, white + empty / 2),
+        }
     }
 
-    #[test]
-    fn index_out_of_bounds() {
-        let board = Board::new();
-        assert_eq!(board.index(0, 8), Err(BoardError::OutOfBounds));
-        assert_eq!(board.index(8, 0), Err(BoardError::OutOfBounds));
-        assert_eq!(board.index(8, 8), Err(BoardError::OutOfBounds));
+    /// `disc`'s legal moves in ascending index order, each listed once. Players rely
+    /// on this order to break ties reproducibly.
+    pub fn valid_moves(&self, disc: Disc) -> ArrayVec<usize, MAX_SURFACE> {
+        bit_indices(self.move_mask(disc)).collect()
     }
 
-    #[test]
-    fn row_col_valid_indices() {
-        let board = Board::new();
-        assert_eq!(board.row_col(0), Ok((0, 0)));
-        assert_eq!(board.row_col(7), Ok((0, 7)),);
-        assert_eq!(board.row_col(56), Ok((7, 0)),);
-        assert_eq!(board.row_col(63), Ok((7, 7)),);
+    /// Whether either side has a legal move, checking White only if Black has none.
+    pub fn any_moves(&self) -> bool {
+        self.move_mask(Disc::Black) != 0 || self.move_mask(Disc::White) != 0
     }
 
-    #[test]
-    fn row_col_out_of_bounds() {
-        let board = Board::new();
-        assert_eq!(board.row_col(64), Err(BoardError::OutOfBounds));
+    /// Number of legal moves for `disc`, counted without building the move list.
+    pub fn mobility(&self, disc: Disc) -> usize {
+        self.move_mask(disc).count_ones() as usize
     }
 
-    #[test]
-    fn get_field_valid() {
-        let board = Board::new();
-        assert_eq!(board.get_field(0), Ok(None));
-        assert_eq!(board.get_field(27), Ok(Some(Disc::White)));
-        assert_eq!(board.get_field(28), Ok(Some(Disc::Black)));
-        assert_eq!(board.get_field(35), Ok(Some(Disc::Black)));
-        assert_eq!(board.get_field(36), Ok(Some(Disc::White)));
-        assert_eq!(board.get_field(63), Ok(None));
+    fn move_mask(&self, disc: Disc) -> u128 {
+        let own = self.discs[disc as usize];
+        let opponent = self.discs[disc.opposite() as usize];
+        let empty = self.empty_mask();
+        let mut moves = 0;
+        for &dir in Direction::ALL.iter() {
+            // opponent runs of growing length that start next to one of our discs
+            let mut run = Self::shift(own, dir) & opponent;
+            while run != 0 {
+                moves |= Self::shift(run, dir) & empty;
+                run = Self::shift(run, dir) & opponent;
+            }
+        }
+        moves
     }
 
-    #[test]
-    fn get_field_out_of_bounds() {
-        let board = Board::new();
-        assert_eq!(board.get_field(64), Err(BoardError::OutOfBounds));
+    pub fn total_capturable(&self, disc: Disc) -> usize {
+        self.valid_moves(disc)
+            .into_iter()
+            .map(|i| self.flip_count(i, disc))
+            .sum()
     }
 
-    #[test]
-    fn set_field_valid() {
-        let mut board = Board::new();
-        assert_eq!(board.get_field(0), Ok(None));
-        assert_eq!(board.set_field(0, Disc::White), Ok(()));
-        assert_eq!(board.get_field(0), Ok(Some(Disc::White)));
-        assert_eq!(board.set_field(0, Disc::Black), Ok(()));
-        assert_eq!(board.get_field(0), Ok(Some(Disc::Black)));
+    /// Number of `disc`'s discs next to at least one empty square, in any of the eight directions.
+    pub fn frontier_discs(&self, disc: Disc) -> usize {
+        let empty = self.empty_mask();
+        let next_to_empty = Direction::ALL
+            .iter()
+            .fold(0, |mask, &dir| mask | Self::shift(empty, dir));
+        (self.discs[disc as usize] & next_to_empty).count_ones() as usize
     }
 
-    #[test]
-    fn set_field_out_of_bounds() {
-        let mut board = Board::new();
-        assert_eq!(
-            board.set_field(64, Disc::White),
-            Err(BoardError::OutOfBounds)
-        );
+    /// The four corner indices in ascending order.
+    pub const CORNERS: [usize; 4] = [0, N - 1, N * (N - 1), N * N - 1];
+
+    /// Which corners `disc` occupies, lowest index first.
+    pub fn corners_held(&self, disc: Disc) -> ArrayVec<usize, 4> {
+        Self::CORNERS
+            .into_iter()
+            .filter(|&index| self.square(index) == Some(disc))
+            .collect()
     }
 
-    #[test]
-    fn next_index_in_bounds() {
-        let board = Board::new();
-        assert_eq!(board.next_index(9, Direction::North), Some(1));
-        assert_eq!(board.next_index(9, Direction::NorthEast), Some(2));
-        assert_eq!(board.next_index(9, Direction::East), Some(10));
-        assert_eq!(board.next_index(9, Direction::SouthEast), Some(18));
-        assert_eq!(board.next_index(9, Direction::South), Some(17));
-        assert_eq!(board.next_index(9, Direction::SouthWest), Some(16));
-        assert_eq!(board.next_index(9, Direction::West), Some(8));
-        assert_eq!(board.next_index(9, Direction::NorthWest), Some(0));
+    pub fn corner_count(&self, disc: Disc) -> usize {
+        self.corners_held(disc).len()
+    }
 
-        assert_eq!(board.next_index(54, Direction::North), Some(46));
-        assert_eq!(board.next_index(54, Direction::NorthEast), Some(47));
-        assert_eq!(board.next_index(54, Direction::East), Some(55));
-        assert_eq!(board.next_index(54, Direction::SouthEast), Some(63));
-        assert_eq!(board.next_index(54, Direction::South), Some(62));
-        assert_eq!(board.next_index(54, Direction::SouthWest), Some(61));
-        assert_eq!(board.next_index(54, Direction::West), Some(53));
-        assert_eq!(board.next_index(54, Direction::NorthWest), Some(45));
+    /// Number of `disc`'s discs that can never be flipped again.
+    ///
+    /// A disc is stable when, along each of the four lines through it, the line is
+    /// full or one of its neighbours on that line is the board edge or another stable
+    /// disc of the same color. Starting from the corners this spreads along edges and
+    /// inwards until nothing changes. It is a lower bound: a few unflippable discs in
+    /// unusual shapes are not detected.
+    pub fn stable_discs(&self, disc: Disc) -> usize {
+        let own = self.discs[disc as usize];
+        let axes = [
+            (Direction::East, Direction::West),
+            (Direction::South, Direction::North),
+            (Direction::SouthEast, Direction::NorthWest),
+            (Direction::SouthWest, Direction::NorthEast),
+        ];
+        let anchored = axes.map(|(a, b)| {
+            let full = self.filled_towards(a) & self.filled_towards(b);
+            full | Self::edge(a) | Self::edge(b)
+        });
+        let mut stable = 0;
+        loop {
+            let next = axes
+                .iter()
+                .zip(anchored)
+                .fold(own, |mask, (&(a, b), anchored)| {
+                    mask & (anchored | Self::shift(stable, a) | Self::shift(stable, b))
+                });
+            if next == stable {
+                return stable.count_ones() as usize;
+            }
+            stable = next;
+        }
     }
 
-    #[test]
-    fn next_index_out_of_bounds() {
-        let board = Board::new();
-        assert_eq!(board.next_index(0, Direction::SouthWest), None);
-        assert_eq!(board.next_index(0, Direction::West), None);
-        assert_eq!(board.next_index(0, Direction::NorthWest), None);
-        assert_eq!(board.next_index(0, Direction::North), None);
-        assert_eq!(board.next_index(0, Direction::NorthEast), None);
+    /// Squares whose neighbour in `dir` is off the board.
+    fn edge(dir: Direction) -> u128 {
+        Self::ALL_SQUARES & !Self::shift(Self::ALL_SQUARES, dir.opposite())
+    }
+
+    /// Occupied squares from which every square up to the edge in `dir` is occupied too.
+    fn filled_towards(&self, dir: Direction) -> u128 {
+        let filled = self.discs[0] | self.discs[1];
+        let edge = Self::edge(dir);
+        let mut mask = filled;
+        for _ in 0..N {
+            mask = filled & (edge | Self::shift(mask, dir.opposite()));
+        }
+        mask
+    }
+
+    /// The legal move leaving the opponent the fewest replies, preferring the lowest index on ties.
+    pub fn move_minimizing_opponent_mobility(&self, disc: Disc) -> Option<usize> {
+        self.valid_moves(disc).into_iter().min_by_key(|&index| {
+            let mut next = self.clone();
+            next.apply_move(index, disc)
+                .expect("valid moves can always be applied");
+            next.valid_moves(disc.opposite()).len()
+        })
+    }
+
+    /// Control of the top, right, bottom and left edges, in that order.
+    pub fn edge_control(&self) -> [EdgeControl; 4] {
+        let last = N - 1;
+        [
+            self.line_control((0..N).map(|col| (0, col))),
+            self.line_control((0..N).map(|row| (row, last))),
+            self.line_control((0..N).map(|col| (last, col))),
+            self.line_control((0..N).map(|row| (row, 0))),
+        ]
+    }
+
+    fn line_control(&self, coords: impl Iterator<Item = (usize, usize)>) -> EdgeControl {
+        let mut control = EdgeControl::default();
+        for (row, col) in coords {
+            match self.square(N * row + col) {
+                Some(Disc::Black) => control.black += 1,
+                Some(Disc::White) => control.white += 1,
+                None => control.empty += 1,
+            }
+        }
+        control.dominant = match (control.black, control.white) {
+            (black, 0) if black > N / 2 => Some(Disc::Black),
+            (0, white) if white > N / 2 => Some(Disc::White),
+            _ => None,
+        };
+        control
+    }
+
+    fn from_fn(square: impl Fn(usize, usize) -> Option<Disc>) -> Self {
+        let mut board = Self::empty();
+        for row in 0..N {
+            for col in 0..N {
+                if let Some(disc) = square(row, col) {
+                    board
+                        .set_field(N * row + col, disc)
+                        .expect("coords within the board are valid");
+                }
+            }
+        }
+        board
+    }
+
+    /// The board rotated a quarter turn clockwise.
+    pub fn rotate90(&self) -> Self {
+        Self::from_fn(|row, col| self.square(N * (N - 1 - col) + row))
+    }
+
+    /// The board mirrored left to right.
+    pub fn mirror_horizontal(&self) -> Self {
+        Self::from_fn(|row, col| self.square(N * row + N - 1 - col))
+    }
+
+    /// All eight rotations and reflections of the board, starting with the board itself.
+    pub fn symmetries(&self) -> [Self; 8] {
+        let r1 = self.rotate90();
+        let r2 = r1.rotate90();
+        let r3 = r2.rotate90();
+        let m0 = self.mirror_horizontal();
+        let m1 = m0.rotate90();
+        let m2 = m1.rotate90();
+        let m3 = m2.rotate90();
+        [self.clone(), r1, r2, r3, m0, m1, m2, m3]
+    }
+
+    /// The representative of this board's symmetry class: the symmetric form whose
+    /// squares compare smallest in index order, with empty < black < white.
+    pub fn canonical(&self) -> Self {
+        let key = |board: &Self| {
+            board
+                .squares()
+                .map(|square| match square {
+                    None => 0,
+                    Some(Disc::Black) => 1,
+                    Some(Disc::White) => 2,
+                })
+                .collect::<ArrayVec<u8, MAX_SURFACE>>()
+        };
+        self.symmetries()
+            .into_iter()
+            .min_by_key(key)
+            .expect("there are always eight symmetries")
+    }
+
+    /// The board with column letters above it and a row number before each row.
+    #[cfg(feature = "alloc")]
+    pub fn display_with_coordinates(&self) -> String {
+        let width = N.to_string().len();
+        let mut out = format!("{:width$}", "");
+        for col in 0..N {
+            out.push(' ');
+            out.push((b'a' + col as u8) as char);
+        }
+        out.push('\n');
+        for row in 0..N {
+            out.push_str(&format!("{:>width$}", row + 1));
+            for square in self.row(row) {
+                out.push(' ');
+                out.push(symbol(square));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// One character per square in index order, `B` black, `W` white and `-` empty,
+    /// then a space and `b` or `w` for `to_move`, the side to move.
+    #[cfg(feature = "alloc")]
+    pub fn to_compact(&self, to_move: Disc) -> String {
+        let mut compact: String = self
+            .squares()
+            .map(|square| square.map_or('-', Disc::to_char))
+            .collect();
+        compact.push(' ');
+        compact.push(to_move.to_char().to_ascii_lowercase());
+        compact
+    }
+
+    /// Parses the format written by `to_compact` into the board and the side to move.
+    pub fn from_compact(compact: &str) -> Result<(Self, Disc), BoardError> {
+        let (squares, to_move) = compact.split_once(' ').ok_or(BoardError::ParseError)?;
+        let to_move = match to_move {
+            "b" => Disc::Black,
+            "w" => Disc::White,
+            _ => return Err(BoardError::ParseError),
+        };
+        if squares.chars().count() != Self::BOARD_SURFACE {
+            return Err(BoardError::ParseError);
+        }
+        let squares = squares
+            .chars()
+            .map(|c| match c {
+                '-' => Ok(None),
+                _ => Disc::from_char(c).map(Some).ok_or(BoardError::ParseError),
+            })
+            .collect::<Result<ArrayVec<_, MAX_SURFACE>, _>>()?;
+        Ok((Self::from_fn(|row, col| squares[N * row + col]), to_move))
+    }
+
+    /// The board as `Display` renders it, with `*` on every square where `disc` may play.
+    #[cfg(feature = "alloc")]
+    pub fn display_with_moves(&self, disc: Disc) -> String {
+        let moves = self.valid_moves(disc);
+        let mut out = String::new();
+        for (index, square) in self.squares().enumerate() {
+            out.push(if moves.contains(&index) {
+                '*'
+            } else {
+                symbol(square)
+            });
+            out.push(' ');
+            if (index + 1) % N == 0 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// The board drawn with ANSI escapes: discs in their own colors on a green background.
+    /// Each row resets the terminal style at its end.
+    #[cfg(feature = "color")]
+    pub fn display_colored(&self) -> String {
+        let mut out = String::new();
+        for row in 0..N {
+            for square in self.row(row) {
+                let glyph = if square.is_some() { '●' } else { '.' };
+                out.push_str(&format!("\x1b[{}m{} ", ansi_style(square), glyph));
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+}
+
+/// The ANSI SGR parameters `display_colored` uses for a square: black discs in black,
+/// white discs in bright white, and empty squares in dark green, all on green.
+#[cfg(feature = "color")]
+pub fn ansi_style(square: Option<Disc>) -> &'static str {
+    match square {
+        Some(Disc::Black) => "30;42",
+        Some(Disc::White) => "97;42",
+        None => "32;42",
+    }
+}
+
+impl StandardBoard {
+    /// The `POSITION_WEIGHTS` of `disc`'s squares minus those of the opponent's.
+    pub fn positional_score(&self, disc: Disc) -> i32 {
+        let weigh = |owner: Disc| -> i32 {
+            bit_indices(self.discs[owner as usize])
+                .map(|index| POSITION_WEIGHTS[index])
+                .sum()
+        };
+        weigh(disc) - weigh(disc.opposite())
+    }
+
+    /// Marks every square `disc` playing on `start` would change: the placed square and
+    /// the discs flipped along each direction. Returns `None` for an illegal move.
+    pub fn move_overlay(&self, start: usize, disc: Disc) -> Option<[MoveMark; 64]> {
+        let Ok(None) = self.get_field(start) else {
+            return None;
+        };
+        let flips = self.all_flips(start, disc)?;
+        let mut overlay = [MoveMark::None; 64];
+        overlay[start] = MoveMark::Placed;
+        for index in flips {
+            overlay[index] = MoveMark::Flipped;
+        }
+        Some(overlay)
+    }
+}
+
+/// Indices of the set bits of `mask`, lowest first.
+fn bit_indices(mut mask: u128) -> impl Iterator<Item = usize> {
+    core::iter::from_fn(move || {
+        if mask == 0 {
+            return None;
+        }
+        let index = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        Some(index)
+    })
+}
+
+fn symbol(square: Option<Disc>) -> char {
+    match square {
+        Some(Disc::Black) => '○',
+        Some(Disc::White) => '●',
+        None => '.',
+    }
+}
+
+impl<const N: usize> Default for Board<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialized as the `N * N` squares in index order; the hash is rebuilt on load.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Board<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.squares())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Board<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let squares = Vec::<Option<Disc>>::deserialize(deserializer)?;
+        if squares.len() != N * N {
+            return Err(serde::de::Error::invalid_length(
+                squares.len(),
+                &format!("{} squares", N * N).as_str(),
+            ));
+        }
+        Ok(Self::from_fn(|row, col| squares[N * row + col]))
+    }
+}
+
+impl<const N: usize> fmt::Display for Board<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..N {
+            for square in self.row(row) {
+                write!(f, "{} ", symbol(square))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Board<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..N {
+            for square in self.row(row) {
+                write!(f, "{} ", symbol(square))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Square-by-square move generation, the reference the bitboard code is checked against.
+    impl<const N: usize> Board<N> {
+        fn flips_in_direction(
+            &self,
+            start: usize,
+            disc: Disc,
+            dir: Direction,
+        ) -> Option<ArrayVec<usize, N>> {
+            let opponent = disc.opposite();
+            let mut flips = ArrayVec::<usize, N>::new();
+            let mut index = self.next_index(start, dir)?;
+            if self.get_field(index).ok()? != Some(opponent) {
+                return None;
+            }
+            flips.push(index);
+            while let Some(next) = self.next_index(index, dir) {
+                index = next;
+                match self.get_field(index).ok()? {
+                    Some(d) if d == opponent => flips.push(index),
+                    Some(d) if d == disc => return Some(flips),
+                    _ => return None,
+                }
+            }
+            None
+        }
+
+        fn reference_valid_moves(&self, disc: Disc) -> Vec<usize> {
+            (0..Self::BOARD_SURFACE)
+                .filter(|&i| self.square(i).is_none())
+                .filter(|&i| {
+                    Direction::ALL
+                        .iter()
+                        .any(|&dir| self.flips_in_direction(i, disc, dir).is_some())
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn index_valid_coordinates() {
+        let board = StandardBoard::new();
+        assert_eq!(board.index(0, 0), Ok(0));
+        assert_eq!(board.index(0, 7), Ok(7));
+        assert_eq!(board.index(7, 0), Ok(56));
+        assert_eq!(board.index(7, 7), Ok(63))
+    }
+
+    #[test]
+    fn index_out_of_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.index(0, 8), Err(BoardError::OutOfBounds));
+        assert_eq!(board.index(8, 0), Err(BoardError::OutOfBounds));
+        assert_eq!(board.index(8, 8), Err(BoardError::OutOfBounds));
+    }
+
+    #[test]
+    fn row_col_valid_indices() {
+        let board = StandardBoard::new();
+        assert_eq!(board.row_col(0), Ok((0, 0)));
+        assert_eq!(board.row_col(7), Ok((0, 7)),);
+        assert_eq!(board.row_col(56), Ok((7, 0)),);
+        assert_eq!(board.row_col(63), Ok((7, 7)),);
+    }
+
+    #[test]
+    fn row_col_out_of_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.row_col(64), Err(BoardError::OutOfBounds));
+    }
+
+    #[test]
+    fn get_field_valid() {
+        let board = StandardBoard::new();
+        assert_eq!(board.get_field(0), Ok(None));
+        assert_eq!(board.get_field(27), Ok(Some(Disc::White)));
+        assert_eq!(board.get_field(28), Ok(Some(Disc::Black)));
+        assert_eq!(board.get_field(35), Ok(Some(Disc::Black)));
+        assert_eq!(board.get_field(36), Ok(Some(Disc::White)));
+        assert_eq!(board.get_field(63), Ok(None));
+    }
+
+    #[test]
+    fn get_field_out_of_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.get_field(64), Err(BoardError::OutOfBounds));
+    }
+
+    #[test]
+    fn owner_at_valid() {
+        let board = StandardBoard::new();
+        assert_eq!(board.owner_at(0, 0), Ok(None));
+        assert_eq!(board.owner_at(3, 3), Ok(Some(Disc::White)));
+        assert_eq!(board.owner_at(3, 4), Ok(Some(Disc::Black)));
+        assert_eq!(board.owner_at(4, 3), Ok(Some(Disc::Black)));
+        assert_eq!(board.owner_at(4, 4), Ok(Some(Disc::White)));
+        assert_eq!(board.owner_at(7, 7), Ok(None));
+    }
+
+    #[test]
+    fn owner_at_out_of_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.owner_at(8, 0), Err(BoardError::OutOfBounds));
+        assert_eq!(board.owner_at(0, 8), Err(BoardError::OutOfBounds));
+    }
+
+    #[test]
+    fn set_field_valid() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.get_field(0), Ok(None));
+        assert_eq!(board.set_field(0, Disc::White), Ok(()));
+        assert_eq!(board.get_field(0), Ok(Some(Disc::White)));
+        assert_eq!(board.set_field(0, Disc::Black), Ok(()));
+        assert_eq!(board.get_field(0), Ok(Some(Disc::Black)));
+    }
+
+    #[test]
+    fn set_field_out_of_bounds() {
+        let mut board = StandardBoard::new();
+        assert_eq!(
+            board.set_field(64, Disc::White),
+            Err(BoardError::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn next_index_in_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.next_index(9, Direction::North), Some(1));
+        assert_eq!(board.next_index(9, Direction::NorthEast), Some(2));
+        assert_eq!(board.next_index(9, Direction::East), Some(10));
+        assert_eq!(board.next_index(9, Direction::SouthEast), Some(18));
+        assert_eq!(board.next_index(9, Direction::South), Some(17));
+        assert_eq!(board.next_index(9, Direction::SouthWest), Some(16));
+        assert_eq!(board.next_index(9, Direction::West), Some(8));
+        assert_eq!(board.next_index(9, Direction::NorthWest), Some(0));
+
+        assert_eq!(board.next_index(54, Direction::North), Some(46));
+        assert_eq!(board.next_index(54, Direction::NorthEast), Some(47));
+        assert_eq!(board.next_index(54, Direction::East), Some(55));
+        assert_eq!(board.next_index(54, Direction::SouthEast), Some(63));
+        assert_eq!(board.next_index(54, Direction::South), Some(62));
+        assert_eq!(board.next_index(54, Direction::SouthWest), Some(61));
+        assert_eq!(board.next_index(54, Direction::West), Some(53));
+        assert_eq!(board.next_index(54, Direction::NorthWest), Some(45));
+    }
+
+    #[test]
+    fn next_index_out_of_bounds() {
+        let board = StandardBoard::new();
+        assert_eq!(board.next_index(0, Direction::SouthWest), None);
+        assert_eq!(board.next_index(0, Direction::West), None);
+        assert_eq!(board.next_index(0, Direction::NorthWest), None);
+        assert_eq!(board.next_index(0, Direction::North), None);
+        assert_eq!(board.next_index(0, Direction::NorthEast), None);
 
         assert_eq!(board.next_index(7, Direction::NorthWest), None);
         assert_eq!(board.next_index(7, Direction::North), None);
@@ -381,7 +1309,7 @@ mod tests {
 
     #[test]
     fn flips_in_direction_some() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
         assert!(board
             .flips_in_direction(44, Disc::Black, Direction::North)
             .is_some());
@@ -402,7 +1330,7 @@ mod tests {
 
     #[test]
     fn flips_in_direction_none() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
         assert!(board
             .flips_in_direction(44, Disc::White, Direction::North)
             .is_none());
@@ -437,9 +1365,80 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flips_along_some() {
+        let mut board = StandardBoard::new();
+        assert_eq!(
+            board.flips_along(44, Disc::Black, Direction::North),
+            Some(vec![36])
+        );
+        assert_eq!(
+            board.flips_along(37, Disc::Black, Direction::West),
+            Some(vec![36])
+        );
+        assert_eq!(
+            board.flips_along(20, Disc::White, Direction::South),
+            Some(vec![28])
+        );
+        assert_eq!(
+            board.flips_along(29, Disc::White, Direction::West),
+            Some(vec![28])
+        );
+        board.set_field(18, Disc::Black).unwrap();
+        assert_eq!(
+            board.flips_along(45, Disc::Black, Direction::NorthWest),
+            Some(vec![36, 27])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flips_along_none() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.flips_along(44, Disc::White, Direction::North), None);
+        assert_eq!(board.flips_along(37, Disc::White, Direction::West), None);
+        assert_eq!(board.flips_along(20, Disc::Black, Direction::South), None);
+        assert_eq!(board.flips_along(29, Disc::Black, Direction::West), None);
+        board.set_field(36, Disc::Black).unwrap();
+        assert_eq!(board.flips_along(44, Disc::Black, Direction::North), None);
+        assert_eq!(board.flips_along(20, Disc::White, Direction::South), None);
+
+        assert_eq!(board.flips_along(0, Disc::White, Direction::North), None);
+        assert_eq!(board.flips_along(0, Disc::Black, Direction::South), None);
+        // an occupied square is not a move, even with a line behind it
+        assert_eq!(board.flips_along(36, Disc::White, Direction::North), None);
+        assert_eq!(board.flips_along(64, Disc::Black, Direction::North), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flips_along_adds_up_to_flips_for() {
+        for board in random_boards::<8>(0xd1e, 20) {
+            for disc in Disc::BOTH {
+                for index in 0..StandardBoard::BOARD_SURFACE {
+                    let mut along: Vec<usize> = Direction::ALL
+                        .iter()
+                        .filter_map(|&dir| board.flips_along(index, disc, dir))
+                        .flatten()
+                        .collect();
+                    along.sort();
+                    let along = Some(along).filter(|flips| !flips.is_empty());
+                    assert_eq!(
+                        along,
+                        board.flips_for(index, disc),
+                        "{} on\n{}",
+                        index,
+                        board
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn all_flips_some() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
         assert!(board.all_flips(44, Disc::Black).is_some());
         assert!(board.all_flips(37, Disc::Black).is_some());
         assert!(board.all_flips(20, Disc::White).is_some());
@@ -450,7 +1449,7 @@ mod tests {
 
     #[test]
     fn all_flips_none() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
         assert!(board.all_flips(44, Disc::White).is_none());
         assert!(board.all_flips(37, Disc::White).is_none());
         assert!(board.all_flips(20, Disc::Black).is_none());
@@ -467,55 +1466,55 @@ mod tests {
 
     #[test]
     fn is_valid_move_valid() {
-        let mut board = Board::new();
-        assert_eq!(board.is_valid_move(44, Disc::Black), true);
-        assert_eq!(board.is_valid_move(37, Disc::Black), true);
-        assert_eq!(board.is_valid_move(20, Disc::White), true);
-        assert_eq!(board.is_valid_move(29, Disc::White), true);
+        let mut board = StandardBoard::new();
+        assert!(board.is_valid_move(44, Disc::Black));
+        assert!(board.is_valid_move(37, Disc::Black));
+        assert!(board.is_valid_move(20, Disc::White));
+        assert!(board.is_valid_move(29, Disc::White));
         board.set_field(18, Disc::Black).unwrap();
-        assert_eq!(board.is_valid_move(45, Disc::Black), true);
+        assert!(board.is_valid_move(45, Disc::Black));
     }
 
     #[test]
     fn is_valid_move_occupied() {
-        let mut board = Board::new();
-        assert_eq!(board.is_valid_move(36, Disc::Black), false);
-        assert_eq!(board.is_valid_move(36, Disc::White), false);
+        let mut board = StandardBoard::new();
+        assert!(!board.is_valid_move(36, Disc::Black));
+        assert!(!board.is_valid_move(36, Disc::White));
         board.set_field(20, Disc::White).unwrap();
-        assert_eq!(board.is_valid_move(36, Disc::Black), false);
-        assert_eq!(board.is_valid_move(36, Disc::White), false);
+        assert!(!board.is_valid_move(36, Disc::Black));
+        assert!(!board.is_valid_move(36, Disc::White));
         board.set_field(36, Disc::Black).unwrap();
-        assert_eq!(board.is_valid_move(36, Disc::Black), false);
-        assert_eq!(board.is_valid_move(36, Disc::White), false);
+        assert!(!board.is_valid_move(36, Disc::Black));
+        assert!(!board.is_valid_move(36, Disc::White));
     }
 
     #[test]
     fn is_valid_move_out_of_bounds() {
-        let board = Board::new();
-        assert_eq!(board.is_valid_move(64, Disc::White), false);
-        assert_eq!(board.is_valid_move(64, Disc::Black), false);
+        let board = StandardBoard::new();
+        assert!(!board.is_valid_move(64, Disc::White));
+        assert!(!board.is_valid_move(64, Disc::Black));
     }
 
     #[test]
     fn is_valid_move_invalid() {
-        let mut board = Board::new();
-        assert_eq!(board.is_valid_move(44, Disc::White), false);
-        assert_eq!(board.is_valid_move(37, Disc::White), false);
-        assert_eq!(board.is_valid_move(20, Disc::Black), false);
-        assert_eq!(board.is_valid_move(29, Disc::Black), false);
+        let mut board = StandardBoard::new();
+        assert!(!board.is_valid_move(44, Disc::White));
+        assert!(!board.is_valid_move(37, Disc::White));
+        assert!(!board.is_valid_move(20, Disc::Black));
+        assert!(!board.is_valid_move(29, Disc::Black));
         board.set_field(36, Disc::Black).unwrap();
-        assert_eq!(board.is_valid_move(44, Disc::Black), false);
-        assert_eq!(board.is_valid_move(20, Disc::White), false);
+        assert!(!board.is_valid_move(44, Disc::Black));
+        assert!(!board.is_valid_move(20, Disc::White));
 
-        assert_eq!(board.is_valid_move(0, Disc::White), false);
-        assert_eq!(board.is_valid_move(0, Disc::White), false);
-        assert_eq!(board.is_valid_move(0, Disc::Black), false);
-        assert_eq!(board.is_valid_move(0, Disc::Black), false);
+        assert!(!board.is_valid_move(0, Disc::White));
+        assert!(!board.is_valid_move(0, Disc::White));
+        assert!(!board.is_valid_move(0, Disc::Black));
+        assert!(!board.is_valid_move(0, Disc::Black));
     }
 
     #[test]
     fn apply_move_valid() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
 
         assert_eq!(board.apply_move(44, Disc::Black), Ok(()));
         assert_eq!(board.get_field(44), Ok(Some(Disc::Black)));
@@ -537,8 +1536,8 @@ mod tests {
 
     #[test]
     fn apply_move_occupied() {
-        let mut board = Board::new();
-        let mut reference = Board::new();
+        let mut board = StandardBoard::new();
+        let mut reference = StandardBoard::new();
 
         // does not have to be a BoardError::SquareOccupied, since BoardError::InvalidMove also applies, as this move would flip nothing
         assert!(board.apply_move(35, Disc::Black).is_err());
@@ -560,8 +1559,8 @@ mod tests {
 
     #[test]
     fn apply_move_out_of_bounds() {
-        let mut board = Board::new();
-        let mut reference = Board::new();
+        let mut board = StandardBoard::new();
+        let mut reference = StandardBoard::new();
 
         // does not have to be a BoardError::OutOfBounds, since BoardError::InvalidMove also applies, as this move would flip nothing
         assert!(board.apply_move(64, Disc::Black).is_err());
@@ -582,8 +1581,8 @@ mod tests {
 
     #[test]
     fn apply_move_invalid() {
-        let mut board = Board::new();
-        let mut reference = Board::new();
+        let mut board = StandardBoard::new();
+        let mut reference = StandardBoard::new();
 
         assert_eq!(
             board.apply_move(0, Disc::Black),
@@ -604,7 +1603,7 @@ mod tests {
 
     #[test]
     fn board_constructor() {
-        let board = Board::new();
+        let board = StandardBoard::new();
 
         for index in 0..64 {
             match index {
@@ -621,13 +1620,48 @@ mod tests {
         assert_eq!(board.count_discs(Disc::Black), black);
         assert_eq!(board.count_discs(Disc::White), white);
         assert_eq!(board.count_empty_squares(), empty);
+        assert_eq!(board.disc_counts(), (black, white, empty));
+    }
+
+    #[test]
+    fn count_discs_matches_full_scan() {
+        let scan = |board: &StandardBoard, disc: Disc| {
+            board
+                .iter_squares()
+                .filter(|&(_, square)| square == Some(disc))
+                .count()
+        };
+        let mut board = StandardBoard::new();
+        let mut disc = Disc::Black;
+        let mut played = Vec::new();
+        while board.any_moves() {
+            if let Some(&index) = board.valid_moves(disc).last() {
+                played.push((
+                    index,
+                    disc,
+                    board.apply_move_returning_flips(index, disc).unwrap(),
+                ));
+            }
+            disc = disc.opposite();
+            for disc in Disc::BOTH {
+                assert_eq!(board.count_discs(disc), scan(&board, disc));
+            }
+        }
+        while let Some((index, disc, flips)) = played.pop() {
+            board.undo_move(index, disc, &flips).unwrap();
+            for disc in Disc::BOTH {
+                assert_eq!(board.count_discs(disc), scan(&board, disc));
+            }
+        }
+        assert_eq!(board, StandardBoard::new());
     }
 
     #[test]
     fn count_discs_and_empty_squares() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
 
         assert_counts(&board, 2, 2, 60);
+        assert_eq!(board.disc_counts(), (2, 2, 60));
 
         // (square_to_play, color_to_play, expected_black, expected_white)
         let moves = [
@@ -645,7 +1679,7 @@ mod tests {
 
     #[test]
     fn valid_moves() {
-        let mut board = Board::new();
+        let mut board = StandardBoard::new();
 
         assert_eq!(&board.valid_moves(Disc::Black)[..], &[19, 26, 37, 44]);
         assert_eq!(&board.valid_moves(Disc::White)[..], &[20, 29, 34, 43]);
@@ -670,4 +1704,1130 @@ mod tests {
         assert_eq!(&board.valid_moves(Disc::Black)[..], &[21, 37, 45]);
         assert_eq!(&board.valid_moves(Disc::White)[..], &[9, 10, 11, 34, 42, 43]);
     }
+
+    #[test]
+    fn total_capturable() {
+        let mut board = StandardBoard::new();
+
+        assert_eq!(board.total_capturable(Disc::Black), 4);
+        assert_eq!(board.total_capturable(Disc::White), 4);
+
+        for &(pos, disc) in &[(19, Disc::Black), (18, Disc::White), (17, Disc::Black)] {
+            board.apply_move(pos, disc).unwrap();
+        }
+
+        for disc in Disc::BOTH {
+            let expected: usize = board
+                .valid_moves(disc)
+                .iter()
+                .map(|&i| board.all_flips(i, disc).map_or(0, |f| f.len()))
+                .sum();
+            assert_eq!(board.total_capturable(disc), expected);
+        }
+        assert_eq!(board.total_capturable(Disc::White), 6);
+    }
+
+    #[test]
+    fn small_board_constructor() {
+        let board = Board::<6>::new();
+
+        assert_eq!(board.index(5, 5), Ok(35));
+        assert_eq!(board.index(0, 6), Err(BoardError::OutOfBounds));
+        for index in 0..36 {
+            match index {
+                14 => assert_eq!(board.get_field(index), Ok(Some(Disc::White))),
+                15 => assert_eq!(board.get_field(index), Ok(Some(Disc::Black))),
+                20 => assert_eq!(board.get_field(index), Ok(Some(Disc::Black))),
+                21 => assert_eq!(board.get_field(index), Ok(Some(Disc::White))),
+                _ => assert_eq!(board.get_field(index), Ok(None)),
+            }
+        }
+        assert_eq!(board.get_field(36), Err(BoardError::OutOfBounds));
+        assert_eq!(board.count_empty_squares(), 32);
+    }
+
+    #[test]
+    fn small_board_valid_moves() {
+        let mut board = Board::<6>::new();
+
+        assert_eq!(&board.valid_moves(Disc::Black)[..], &[8, 13, 22, 27]);
+        assert_eq!(&board.valid_moves(Disc::White)[..], &[9, 16, 19, 26]);
+
+        board.apply_move(8, Disc::Black).unwrap();
+        assert_eq!(board.get_field(14), Ok(Some(Disc::Black)));
+        assert_eq!(&board.valid_moves(Disc::White)[..], &[7, 9, 19]);
+    }
+
+    fn full_hash<const N: usize>(board: &Board<N>) -> u64 {
+        (0..Board::<N>::BOARD_SURFACE)
+            .filter_map(|i| {
+                let disc = board.get_field(i).unwrap()?;
+                Some(Board::<N>::zobrist_key(i, disc))
+            })
+            .fold(0, |hash, key| hash ^ key)
+    }
+
+    #[test]
+    fn zobrist_hash_transpositions() {
+        let mut first = StandardBoard::new();
+        let mut second = StandardBoard::new();
+        assert_eq!(first.zobrist_hash(), second.zobrist_hash());
+
+        let first_order = [
+            (44, Disc::Black),
+            (45, Disc::White),
+            (46, Disc::Black),
+            (43, Disc::White),
+            (42, Disc::Black),
+        ];
+        let second_order = [
+            (44, Disc::Black),
+            (43, Disc::White),
+            (42, Disc::Black),
+            (45, Disc::White),
+            (46, Disc::Black),
+        ];
+        for &(pos, disc) in &first_order {
+            first.apply_move(pos, disc).unwrap();
+        }
+        for &(pos, disc) in &second_order {
+            second.apply_move(pos, disc).unwrap();
+        }
+
+        assert_eq!(first, second);
+        assert_eq!(first.zobrist_hash(), second.zobrist_hash());
+        assert_eq!(first.zobrist_hash(), full_hash(&first));
+    }
+
+    #[test]
+    fn zobrist_hash_changes_on_move() {
+        let mut board = StandardBoard::new();
+        let before = board.zobrist_hash();
+        assert_eq!(before, full_hash(&board));
+
+        let flips = board.apply_move_returning_flips(19, Disc::Black).unwrap();
+        assert_eq!(&flips[..], &[27]);
+        assert_ne!(board.zobrist_hash(), before);
+        assert_eq!(board.zobrist_hash(), full_hash(&board));
+        assert_eq!(
+            before
+                ^ StandardBoard::zobrist_key(19, Disc::Black)
+                ^ StandardBoard::zobrist_key(27, Disc::White)
+                ^ StandardBoard::zobrist_key(27, Disc::Black),
+            board.zobrist_hash()
+        );
+    }
+
+    #[test]
+    fn edge_control_full_edge() {
+        let mut board = StandardBoard::new();
+        for index in 0..8 {
+            board.set_field(index, Disc::Black).unwrap();
+        }
+        board.set_field(63, Disc::White).unwrap();
+
+        let [top, right, bottom, left] = board.edge_control();
+        assert_eq!(
+            top,
+            EdgeControl {
+                black: 8,
+                white: 0,
+                empty: 0,
+                dominant: Some(Disc::Black)
+            }
+        );
+        assert_eq!(
+            right,
+            EdgeControl {
+                black: 1,
+                white: 1,
+                empty: 6,
+                dominant: None
+            }
+        );
+        assert_eq!((bottom.white, bottom.empty, bottom.dominant), (1, 7, None));
+        assert_eq!((left.black, left.empty, left.dominant), (1, 7, None));
+    }
+
+    #[test]
+    fn rotate90_four_times_is_identity() {
+        let mut board = StandardBoard::new();
+        board.apply_move(19, Disc::Black).unwrap();
+        board.apply_move(18, Disc::White).unwrap();
+
+        let rotated = board.rotate90();
+        assert_ne!(rotated, board);
+        assert_eq!(rotated.get_field(21), Ok(Some(Disc::White)));
+        assert_eq!(rotated.rotate90().rotate90().rotate90(), board);
+        assert_eq!(board.mirror_horizontal().mirror_horizontal(), board);
+    }
+
+    #[test]
+    fn canonical_is_symmetry_invariant() {
+        let mut board = StandardBoard::new();
+        for &(pos, disc) in &[(19, Disc::Black), (18, Disc::White), (17, Disc::Black)] {
+            board.apply_move(pos, disc).unwrap();
+        }
+
+        let canonical = board.canonical();
+        for symmetric in board.symmetries() {
+            assert_eq!(symmetric.canonical(), canonical);
+        }
+    }
+
+    #[test]
+    fn move_minimizing_opponent_mobility() {
+        let mut board = StandardBoard::new();
+        board.apply_move(19, Disc::Black).unwrap();
+        board.apply_move(18, Disc::White).unwrap();
+
+        // black's replies 17, 26, 37 and 44 leave white 6, 2, 5 and 5 moves respectively
+        assert_eq!(
+            board.move_minimizing_opponent_mobility(Disc::Black),
+            Some(26)
+        );
+        // all four opening moves leave the opponent three replies, so the lowest index wins
+        assert_eq!(
+            StandardBoard::new().move_minimizing_opponent_mobility(Disc::Black),
+            Some(19)
+        );
+        assert_eq!(
+            StandardBoard::from_fn(|_, _| None).move_minimizing_opponent_mobility(Disc::Black),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn algebraic_round_trip() {
+        for index in 0..64 {
+            let notation = StandardBoard::algebraic_from_index(index).unwrap();
+            assert_eq!(StandardBoard::index_from_algebraic(&notation), Ok(index));
+        }
+        assert_eq!(StandardBoard::algebraic_from_index(0), Ok("a1".to_string()));
+        assert_eq!(
+            StandardBoard::algebraic_from_index(19),
+            Ok("d3".to_string())
+        );
+        assert_eq!(
+            StandardBoard::algebraic_from_index(63),
+            Ok("h8".to_string())
+        );
+        assert_eq!(
+            StandardBoard::algebraic_from_index(64),
+            Err(BoardError::OutOfBounds)
+        );
+        assert_eq!(Board::<10>::algebraic_from_index(99), Ok("j10".to_string()));
+    }
+
+    #[test]
+    fn algebraic_parsing() {
+        assert_eq!(StandardBoard::index_from_algebraic("d3"), Ok(19));
+        assert_eq!(StandardBoard::index_from_algebraic("D3"), Ok(19));
+        assert_eq!(StandardBoard::index_from_algebraic("H8"), Ok(63));
+
+        for notation in ["i1", "a9", "a10", "z9"] {
+            assert_eq!(
+                StandardBoard::index_from_algebraic(notation),
+                Err(BoardError::OutOfBounds),
+                "{}",
+                notation
+            );
+        }
+        for notation in ["", "a", "3d", "a0", "a01", "a+1", "d3 ", "é3", "dd3"] {
+            assert_eq!(
+                StandardBoard::index_from_algebraic(notation),
+                Err(BoardError::ParseError),
+                "{}",
+                notation
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn display_with_coordinates() {
+        let expected = "  a b c d e f g h\n\
+                        1 . . . . . . . .\n\
+                        2 . . . . . . . .\n\
+                        3 . . . . . . . .\n\
+                        4 . . . ● ○ . . .\n\
+                        5 . . . ○ ● . . .\n\
+                        6 . . . . . . . .\n\
+                        7 . . . . . . . .\n\
+                        8 . . . . . . . .\n";
+        assert_eq!(StandardBoard::new().display_with_coordinates(), expected);
+
+        let wide = Board::<10>::new().display_with_coordinates();
+        assert_eq!(wide.lines().next(), Some("   a b c d e f g h i j"));
+        assert_eq!(wide.lines().last(), Some("10 . . . . . . . . . ."));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn display_with_moves() {
+        let board = StandardBoard::new();
+        let rendered = board.display_with_moves(Disc::Black);
+        let marked: Vec<usize> = rendered
+            .lines()
+            .flat_map(|line| line.split(' ').filter(|s| !s.is_empty()))
+            .enumerate()
+            .filter(|&(_, s)| s == "*")
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(marked, &board.valid_moves(Disc::Black)[..]);
+        assert_eq!(marked, [19, 26, 37, 44]);
+        assert_eq!(rendered.lines().nth(3), Some(". . * ● ○ . . . "));
+        assert_eq!(rendered.replace('*', "."), board.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let mut mid_game = StandardBoard::new();
+        for (index, disc) in [(19, Disc::Black), (18, Disc::White), (17, Disc::Black)] {
+            mid_game.apply_move(index, disc).unwrap();
+        }
+        for board in [StandardBoard::new(), mid_game] {
+            let json = serde_json::to_string(&board).unwrap();
+            let restored: StandardBoard = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, board);
+            assert_eq!(restored.zobrist_hash(), board.zobrist_hash());
+        }
+
+        let json = serde_json::to_string(&Board::<6>::new()).unwrap();
+        assert!(serde_json::from_str::<StandardBoard>(&json).is_err());
+    }
+
+    fn random_boards<const N: usize>(seed: u64, count: usize) -> Vec<Board<N>> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut boards = Vec::new();
+        for _ in 0..count {
+            // arbitrary fills reach edge cases real games rarely do
+            let density = next() % 4;
+            let squares: Vec<Option<Disc>> = (0..N * N)
+                .map(|_| match next() % 4 {
+                    r if r < density => None,
+                    r if r % 2 == 0 => Some(Disc::Black),
+                    _ => Some(Disc::White),
+                })
+                .collect();
+            boards.push(Board::<N>::from_fn(|row, col| squares[N * row + col]));
+
+            let mut board = Board::<N>::new();
+            let mut disc = Disc::Black;
+            for _ in 0..next() % (N * N) as u64 {
+                let moves = board.reference_valid_moves(disc);
+                if !moves.is_empty() {
+                    let index = moves[next() as usize % moves.len()];
+                    board.apply_move(index, disc).unwrap();
+                }
+                disc = disc.opposite();
+            }
+            boards.push(board);
+        }
+        boards
+    }
+
+    fn assert_matches_reference<const N: usize>(boards: &[Board<N>]) {
+        for board in boards {
+            for disc in Disc::BOTH {
+                assert_eq!(
+                    &board.valid_moves(disc)[..],
+                    &board.reference_valid_moves(disc)[..],
+                    "{:?} on\n{}",
+                    disc,
+                    board
+                );
+                for index in 0..Board::<N>::BOARD_SURFACE {
+                    let mut expected: Vec<usize> = Direction::ALL
+                        .iter()
+                        .filter_map(|&dir| board.flips_in_direction(index, disc, dir))
+                        .flatten()
+                        .collect();
+                    expected.sort();
+                    let mut flips = board.all_flips(index, disc).unwrap_or_default().to_vec();
+                    flips.sort();
+                    assert_eq!(flips, expected, "{:?} at {} on\n{}", disc, index, board);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bitboard_matches_reference() {
+        assert_matches_reference(&random_boards::<8>(0x2545_f491_4f6c_dd1d, 200));
+        assert_matches_reference(&random_boards::<4>(7, 100));
+        assert_matches_reference(&random_boards::<6>(11, 100));
+        assert_matches_reference(&random_boards::<10>(13, 100));
+    }
+
+    #[test]
+    fn frontier_discs() {
+        let board = StandardBoard::new();
+        assert_eq!(board.frontier_discs(Disc::Black), 2);
+        assert_eq!(board.frontier_discs(Disc::White), 2);
+
+        // a 3x3 black block in the corner: the 2x2 block touching the corner is sealed
+        // in by the board edge and the rest of the block, leaving five frontier discs
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (0..=2, 0..=2) => Some(Disc::Black),
+            (3, 3) => Some(Disc::White),
+            _ => None,
+        });
+        assert_eq!(board.frontier_discs(Disc::Black), 5);
+        assert_eq!(board.frontier_discs(Disc::White), 1);
+    }
+
+    #[test]
+    fn corners_held() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.corner_count(Disc::Black), 0);
+        assert!(board.corners_held(Disc::White).is_empty());
+
+        board.set_field(56, Disc::Black).unwrap();
+        board.set_field(7, Disc::Black).unwrap();
+        board.set_field(63, Disc::White).unwrap();
+        assert_eq!(&board.corners_held(Disc::Black)[..], &[7, 56]);
+        assert_eq!(board.corner_count(Disc::Black), 2);
+        assert_eq!(&board.corners_held(Disc::White)[..], &[63]);
+        assert_eq!(board.corner_count(Disc::White), 1);
+    }
+
+    #[test]
+    fn stable_discs() {
+        let board = StandardBoard::new();
+        assert_eq!(board.stable_discs(Disc::Black), 0);
+        assert_eq!(board.stable_discs(Disc::White), 0);
+
+        // a fully owned top edge, plus an isolated disc of each color in the middle
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (0, _) | (3, 3) => Some(Disc::Black),
+            (4, 4) => Some(Disc::White),
+            _ => None,
+        });
+        assert_eq!(board.stable_discs(Disc::Black), 8);
+        assert_eq!(board.stable_discs(Disc::White), 0);
+
+        // a partial edge is only stable when anchored on a corner
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (0, 0..=2) | (7, 2..=5) => Some(Disc::Black),
+            (0, 3) => Some(Disc::White),
+            _ => None,
+        });
+        assert_eq!(board.stable_discs(Disc::Black), 3);
+        assert_eq!(board.stable_discs(Disc::White), 0);
+
+        // a full edge is stable whoever owns each square
+        let board = StandardBoard::from_fn(|row, col| match (row, col % 2) {
+            (0, 0) => Some(Disc::Black),
+            (0, _) => Some(Disc::White),
+            _ => None,
+        });
+        assert_eq!(board.stable_discs(Disc::Black), 4);
+        assert_eq!(board.stable_discs(Disc::White), 4);
+    }
+
+    #[test]
+    fn empty_count_and_is_full() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.empty_count(), 60);
+        assert!(!board.is_full());
+
+        for (index, disc) in [(19, Disc::Black), (18, Disc::White), (17, Disc::Black)] {
+            board.apply_move(index, disc).unwrap();
+        }
+        assert_eq!(board.empty_count(), 57);
+        assert_eq!(
+            board.empty_count(),
+            64 - board.count_discs(Disc::Black) - board.count_discs(Disc::White)
+        );
+
+        let full =
+            StandardBoard::from_fn(|row, _| Some(if row < 4 { Disc::Black } else { Disc::White }));
+        assert_eq!(full.empty_count(), 0);
+        assert!(full.is_full());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn empty_regions() {
+        assert_eq!(StandardBoard::new().empty_regions().len(), 1);
+        assert_eq!(StandardBoard::new().empty_regions()[0].len(), 60);
+
+        // a pocket of three in the top-left corner and a single square in the bottom-right
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (0, 0) | (0, 1) | (1, 0) | (7, 7) => None,
+            _ => Some(Disc::Black),
+        });
+        assert_eq!(board.empty_regions(), vec![vec![0, 1, 8], vec![63]]);
+
+        // diagonal neighbours are not connected, and rows do not wrap around
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (2, 2) | (3, 3) | (4, 7) | (5, 0) => None,
+            _ => Some(Disc::White),
+        });
+        assert_eq!(
+            board.empty_regions(),
+            vec![vec![18], vec![27], vec![39], vec![40]]
+        );
+        let full =
+            StandardBoard::from_fn(|row, _| Some(if row < 4 { Disc::Black } else { Disc::White }));
+        assert!(full.empty_regions().is_empty());
+    }
+
+    #[test]
+    fn any_moves() {
+        assert!(StandardBoard::new().any_moves());
+
+        // with only white discs left neither side can flip anything
+        let stuck = StandardBoard::set_position(&[(27, Disc::White), (28, Disc::White)]).unwrap();
+        assert!(!stuck.any_moves());
+        let full =
+            StandardBoard::from_fn(|row, _| Some(if row < 4 { Disc::Black } else { Disc::White }));
+        assert!(!full.any_moves());
+
+        // only white has a move here
+        let board = StandardBoard::set_position(&[(0, Disc::White), (1, Disc::Black)]).unwrap();
+        assert!(board.valid_moves(Disc::Black).is_empty());
+        assert!(board.any_moves());
+    }
+
+    #[test]
+    fn iter_squares() {
+        let board = StandardBoard::new();
+        let squares: Vec<_> = board.iter_squares().collect();
+        assert_eq!(squares.len(), 64);
+        assert!(squares
+            .iter()
+            .enumerate()
+            .all(|(i, &(index, _))| i == index));
+        let occupied: Vec<_> = squares
+            .into_iter()
+            .filter_map(|(index, square)| square.map(|disc| (index, disc)))
+            .collect();
+        assert_eq!(
+            occupied,
+            [
+                (27, Disc::White),
+                (28, Disc::Black),
+                (35, Disc::Black),
+                (36, Disc::White)
+            ]
+        );
+    }
+
+    #[test]
+    fn flip_count_matches_all_flips() {
+        let mut boards = random_boards::<8>(0x9e37_79b9, 20);
+        boards.push(StandardBoard::new());
+        for board in &boards {
+            for disc in Disc::BOTH {
+                for index in 0..64 {
+                    let expected = match board.get_field(index) {
+                        Ok(None) => board.all_flips(index, disc).map(|v| v.len()).unwrap_or(0),
+                        _ => 0,
+                    };
+                    assert_eq!(board.flip_count(index, disc), expected);
+                }
+            }
+        }
+        assert_eq!(StandardBoard::new().flip_count(27, Disc::Black), 0);
+        assert_eq!(StandardBoard::new().flip_count(19, Disc::Black), 1);
+        assert_eq!(StandardBoard::new().flip_count(64, Disc::Black), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compact_round_trip() {
+        let opening = StandardBoard::new();
+        assert_eq!(
+            opening.to_compact(Disc::Black),
+            "---------------------------WB------BW--------------------------- b"
+        );
+        let mut boards = random_boards::<8>(0x5eed, 20);
+        boards.push(opening);
+        for board in boards {
+            for disc in Disc::BOTH {
+                let (restored, to_move) =
+                    StandardBoard::from_compact(&board.to_compact(disc)).unwrap();
+                assert_eq!(restored, board);
+                assert_eq!(to_move, disc);
+                assert_eq!(restored.zobrist_hash(), board.zobrist_hash());
+            }
+        }
+        let small = Board::<6>::new().to_compact(Disc::White);
+        assert_eq!(
+            Board::<6>::from_compact(&small),
+            Ok((Board::<6>::new(), Disc::White))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compact_rejects_bad_input() {
+        let compact = StandardBoard::new().to_compact(Disc::Black);
+        assert_eq!(
+            StandardBoard::from_compact(&compact[1..]),
+            Err(BoardError::ParseError)
+        );
+        assert_eq!(
+            StandardBoard::from_compact(&format!("{}-", compact)),
+            Err(BoardError::ParseError)
+        );
+        assert_eq!(
+            StandardBoard::from_compact(&compact.replace('W', "x")),
+            Err(BoardError::ParseError)
+        );
+        assert_eq!(
+            StandardBoard::from_compact(&compact.to_lowercase()),
+            Err(BoardError::ParseError)
+        );
+        // the side to move is required, lowercase, and alone
+        for side in ["", " ", " B", " x", " b ", " bw"] {
+            let input = format!("{}{}", &compact[..64], side);
+            assert_eq!(
+                StandardBoard::from_compact(&input),
+                Err(BoardError::ParseError),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn undo_move_restores_board() {
+        let mut board = StandardBoard::new();
+        board.apply_move(19, Disc::Black).unwrap();
+        let before = board.clone();
+        let flips = board.apply_move_returning_flips(18, Disc::White).unwrap();
+
+        assert_eq!(
+            board.undo_move(18, Disc::Black, &flips),
+            Err(BoardError::InvalidMove)
+        );
+        assert_eq!(
+            board.undo_move(17, Disc::White, &flips),
+            Err(BoardError::InvalidMove)
+        );
+        assert_eq!(
+            board.undo_move(64, Disc::White, &flips),
+            Err(BoardError::OutOfBounds)
+        );
+        assert_eq!(board.undo_move(18, Disc::White, &flips), Ok(()));
+        assert_eq!(board, before);
+        assert_eq!(board.zobrist_hash(), before.zobrist_hash());
+    }
+
+    #[test]
+    fn neighbors() {
+        let board = StandardBoard::new();
+        let indices =
+            |index| -> Vec<usize> { board.neighbors(index).iter().map(|&(i, _)| i).collect() };
+        assert_eq!(indices(0), [1, 9, 8]);
+        assert_eq!(indices(4), [5, 13, 12, 11, 3]);
+        assert_eq!(indices(63), [55, 62, 54]);
+        assert_eq!(indices(18), [10, 11, 19, 27, 26, 25, 17, 9]);
+        assert!(indices(64).is_empty());
+
+        let occupied: Vec<_> = board
+            .neighbors(19)
+            .into_iter()
+            .filter(|(_, square)| square.is_some())
+            .collect();
+        assert_eq!(occupied, [(28, Some(Disc::Black)), (27, Some(Disc::White))]);
+    }
+
+    #[test]
+    fn mobility() {
+        let board = StandardBoard::new();
+        assert_eq!(board.mobility(Disc::Black), 4);
+        assert_eq!(board.mobility(Disc::White), 4);
+
+        // one empty corner left; only black can close the top edge on it
+        let board = StandardBoard::from_fn(|row, col| match (row, col) {
+            (0, 0) => None,
+            (0, 1) => Some(Disc::White),
+            _ => Some(Disc::Black),
+        });
+        assert_eq!(board.mobility(Disc::Black), 1);
+        assert_eq!(board.mobility(Disc::White), 0);
+
+        for board in random_boards::<8>(0xfeed, 20) {
+            for disc in Disc::BOTH {
+                assert_eq!(board.mobility(disc), board.valid_moves(disc).len());
+            }
+        }
+    }
+
+    #[test]
+    fn try_apply_matches_apply_move() {
+        for board in random_boards::<8>(0x7a11, 30) {
+            for disc in Disc::BOTH {
+                for index in 0..StandardBoard::BOARD_SURFACE {
+                    let mut tried = board.clone();
+                    let mut applied = board.clone();
+                    let result = tried.try_apply(index, disc);
+                    match applied.apply_move_returning_flips(index, disc) {
+                        Ok(flips) => assert_eq!(result, Ok(flips.len() as u32)),
+                        Err(err) => assert_eq!(result, Err(err)),
+                    }
+                    assert_eq!(tried, applied);
+                    assert_eq!(tried.zobrist_hash(), full_hash(&tried));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn apply_move_unchecked_matches_apply_move() {
+        for board in random_boards::<8>(0x0c4e, 20) {
+            for disc in Disc::BOTH {
+                for index in board.valid_moves(disc) {
+                    let flips = board.flips_for(index, disc).unwrap();
+                    let mut unchecked = board.clone();
+                    let mut applied = board.clone();
+                    unchecked.apply_move_unchecked(index, disc, &flips);
+                    applied.apply_move(index, disc).unwrap();
+                    assert_eq!(unchecked, applied);
+                    assert_eq!(unchecked.zobrist_hash(), full_hash(&unchecked));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn try_apply_illegal_leaves_board_unchanged() {
+        let mut board = StandardBoard::new();
+        let reference = board.clone();
+        assert_eq!(
+            board.try_apply(0, Disc::Black),
+            Err(BoardError::InvalidMove)
+        );
+        assert_eq!(
+            board.try_apply(27, Disc::Black),
+            Err(BoardError::SquareOccupied)
+        );
+        assert_eq!(
+            board.try_apply(64, Disc::White),
+            Err(BoardError::OutOfBounds)
+        );
+        assert_eq!(
+            board.try_apply(44, Disc::White),
+            Err(BoardError::InvalidMove)
+        );
+        assert_eq!(board, reference);
+        assert_eq!(board.zobrist_hash(), reference.zobrist_hash());
+
+        assert_eq!(board.try_apply(44, Disc::Black), Ok(1));
+        assert_eq!(board.get_field(36), Ok(Some(Disc::Black)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flips_for_some() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.flips_for(44, Disc::Black), Some(vec![36]));
+        assert_eq!(board.flips_for(37, Disc::Black), Some(vec![36]));
+        assert_eq!(board.flips_for(20, Disc::White), Some(vec![28]));
+        assert_eq!(board.flips_for(29, Disc::White), Some(vec![28]));
+        board.set_field(18, Disc::Black).unwrap();
+        assert_eq!(board.flips_for(45, Disc::Black), Some(vec![27, 36]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn flips_for_none() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.flips_for(44, Disc::White), None);
+        assert_eq!(board.flips_for(37, Disc::White), None);
+        assert_eq!(board.flips_for(20, Disc::Black), None);
+        assert_eq!(board.flips_for(29, Disc::Black), None);
+        board.set_field(36, Disc::Black).unwrap();
+        assert_eq!(board.flips_for(44, Disc::Black), None);
+        assert_eq!(board.flips_for(20, Disc::White), None);
+
+        assert_eq!(board.flips_for(0, Disc::White), None);
+        assert_eq!(board.flips_for(0, Disc::Black), None);
+        assert_eq!(board.flips_for(36, Disc::White), None);
+        assert_eq!(board.flips_for(64, Disc::Black), None);
+    }
+
+    #[test]
+    fn empty_parity() {
+        let mut board = StandardBoard::new();
+        assert_eq!(board.empty_parity(), Parity::Even);
+        board.apply_move(19, Disc::Black).unwrap();
+        assert_eq!(board.empty_parity(), Parity::Odd);
+        board.apply_move(18, Disc::White).unwrap();
+        board.apply_move(17, Disc::Black).unwrap();
+        assert_eq!(board.empty_parity(), Parity::Odd);
+        assert_eq!(Board::<6>::new().empty_parity(), Parity::Even);
+    }
+
+    #[test]
+    fn set_position() {
+        let board = StandardBoard::set_position(&[
+            (27, Disc::White),
+            (28, Disc::Black),
+            (35, Disc::Black),
+            (36, Disc::White),
+        ]);
+        assert_eq!(board, Ok(StandardBoard::new()));
+
+        let board = Board::<6>::set_position(&[(0, Disc::Black), (35, Disc::White)]).unwrap();
+        assert_eq!(board.get_field(0), Ok(Some(Disc::Black)));
+        assert_eq!(board.get_field(35), Ok(Some(Disc::White)));
+        assert_eq!(board.count_empty_squares(), 34);
+        assert_eq!(board.zobrist_hash(), full_hash(&board));
+        assert_eq!(
+            StandardBoard::set_position(&[]).map(|b| b.is_full()),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn with_opening() {
+        assert_eq!(
+            StandardBoard::with_opening(OpeningStyle::Standard),
+            StandardBoard::new()
+        );
+        let standard = StandardBoard::new();
+        let reversed = StandardBoard::with_opening(OpeningStyle::Reversed);
+        for index in [27, 28, 35, 36] {
+            let disc = standard.get_field(index).unwrap().unwrap();
+            assert_eq!(reversed.get_field(index), Ok(Some(disc.opposite())));
+        }
+        assert_eq!(reversed.get_field(27), Ok(Some(Disc::Black)));
+        assert_eq!(reversed.get_field(28), Ok(Some(Disc::White)));
+        assert_eq!(reversed.count_empty_squares(), 60);
+        assert_eq!(reversed.zobrist_hash(), full_hash(&reversed));
+
+        let small = Board::<4>::with_opening(OpeningStyle::Reversed);
+        assert_eq!(small.get_field(5), Ok(Some(Disc::Black)));
+        assert_eq!(small.get_field(6), Ok(Some(Disc::White)));
+    }
+
+    #[test]
+    fn from_rows() {
+        const E: Option<Disc> = None;
+        const B: Option<Disc> = Some(Disc::Black);
+        const W: Option<Disc> = Some(Disc::White);
+        let board = StandardBoard::from_rows([
+            [E, E, E, E, E, E, E, E],
+            [E, E, E, E, E, E, E, E],
+            [E, E, E, E, E, E, E, E],
+            [E, E, E, W, B, E, E, E],
+            [E, E, E, B, W, E, E, E],
+            [E, E, E, E, E, E, E, E],
+            [E, E, E, E, E, E, E, E],
+            [E, E, E, E, E, E, E, E],
+        ]);
+        assert_eq!(board, StandardBoard::new());
+
+        let board = Board::<4>::from_rows([[B, E, E, E], [E, E, E, E], [E, E, E, E], [E, E, E, W]]);
+        assert_eq!(board.get_field(0), Ok(Some(Disc::Black)));
+        assert_eq!(board.get_field(15), Ok(Some(Disc::White)));
+        assert_eq!(board.zobrist_hash(), full_hash(&board));
+    }
+
+    #[test]
+    fn set_position_rejects_bad_placements() {
+        assert_eq!(
+            StandardBoard::set_position(&[(3, Disc::Black), (3, Disc::White)]),
+            Err(BoardError::SquareOccupied)
+        );
+        assert_eq!(
+            StandardBoard::set_position(&[(3, Disc::Black), (3, Disc::Black)]),
+            Err(BoardError::SquareOccupied)
+        );
+        assert_eq!(
+            StandardBoard::set_position(&[(0, Disc::Black), (64, Disc::White)]),
+            Err(BoardError::OutOfBounds)
+        );
+        assert_eq!(
+            Board::<4>::set_position(&[(16, Disc::White)]),
+            Err(BoardError::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn empty_board() {
+        let board = StandardBoard::empty();
+        assert_eq!(board.count_discs(Disc::Black), 0);
+        assert_eq!(board.count_discs(Disc::White), 0);
+        assert_eq!(board.iter_squares().count(), 64);
+        assert!(board.iter_squares().all(|(_, square)| square.is_none()));
+        assert_eq!(board.zobrist_hash(), 0);
+        assert_ne!(board, StandardBoard::new());
+        assert!(board.valid_moves(Disc::Black).is_empty());
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn ansi_style_mapping() {
+        let styles = [None, Some(Disc::Black), Some(Disc::White)].map(ansi_style);
+        assert_eq!(styles, ["32;42", "30;42", "97;42"]);
+        assert!(styles.iter().all(|style| style.ends_with(";42")));
+
+        let colored = StandardBoard::new().display_colored();
+        assert_eq!(colored.lines().count(), 8);
+        assert_eq!(colored.matches("\x1b[30;42m●").count(), 2);
+        assert_eq!(colored.matches("\x1b[97;42m●").count(), 2);
+        assert!(colored.lines().all(|line| line.ends_with("\x1b[0m")));
+    }
+
+    #[test]
+    fn board_error_display() {
+        assert_eq!(
+            BoardError::OutOfBounds.to_string(),
+            "square is off the board"
+        );
+        assert_eq!(
+            BoardError::SquareOccupied.to_string(),
+            "square already occupied"
+        );
+        assert_eq!(BoardError::InvalidMove.to_string(), "move is not legal");
+        assert_eq!(
+            BoardError::ParseError.to_string(),
+            "could not parse board notation"
+        );
+    }
+
+    #[test]
+    fn line_iter() {
+        let board = StandardBoard::new();
+        let east: Vec<usize> = board
+            .line_iter(0, Direction::East)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(east, [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(board.line_iter(7, Direction::East).count(), 0);
+        assert_eq!(board.line_iter(64, Direction::East).count(), 0);
+
+        let diagonal: Vec<_> = board.line_iter(0, Direction::SouthEast).collect();
+        assert_eq!(diagonal.len(), 7);
+        assert_eq!(diagonal[2], (27, Some(Disc::White)));
+        assert_eq!(diagonal[3], (36, Some(Disc::White)));
+        assert_eq!(diagonal[6], (63, None));
+
+        let north: Vec<_> = board.line_iter(35, Direction::North).collect();
+        assert_eq!(
+            north,
+            [(27, Some(Disc::White)), (19, None), (11, None), (3, None)]
+        );
+    }
+
+    #[test]
+    fn positional_score() {
+        let board = StandardBoard::new();
+        assert_eq!(board.positional_score(Disc::Black), 0);
+
+        let corner = StandardBoard::set_position(&[(0, Disc::Black), (27, Disc::White)]).unwrap();
+        assert_eq!(corner.positional_score(Disc::Black), 101);
+        assert_eq!(corner.positional_score(Disc::White), -101);
+
+        let x_square = StandardBoard::set_position(&[(9, Disc::Black)]).unwrap();
+        assert_eq!(x_square.positional_score(Disc::Black), -50);
+        assert_eq!(x_square.positional_score(Disc::White), 50);
+
+        // the table is symmetric, so every orientation scores the same
+        let board = random_boards::<8>(0x5eed, 1).remove(0);
+        for symmetry in board.symmetries() {
+            assert_eq!(
+                symmetry.positional_score(Disc::White),
+                board.positional_score(Disc::White)
+            );
+        }
+    }
+
+    #[test]
+    fn move_overlay() {
+        let board = StandardBoard::new();
+        let overlay = board.move_overlay(19, Disc::Black).unwrap();
+        let marked = |mark| {
+            (0..64)
+                .filter(|&index| overlay[index] == mark)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(marked(MoveMark::Placed), [19]);
+        assert_eq!(marked(MoveMark::Flipped), [27]);
+
+        // a white reply later in the opening
+        let mut board = StandardBoard::new();
+        board.apply_move(19, Disc::Black).unwrap();
+        board.apply_move(18, Disc::White).unwrap();
+        board.apply_move(17, Disc::Black).unwrap();
+        let overlay = board.move_overlay(9, Disc::White).unwrap();
+        let flipped: Vec<usize> = (0..64)
+            .filter(|&index| overlay[index] == MoveMark::Flipped)
+            .collect();
+        assert_eq!(flipped, [18]);
+
+        assert_eq!(board.move_overlay(0, Disc::Black), None);
+        assert_eq!(board.move_overlay(27, Disc::Black), None);
+    }
+
+    #[test]
+    fn tournament_score() {
+        let board = StandardBoard::new();
+        assert_eq!(board.tournament_score(), (2, 2));
+
+        // a wiped-out side ends the game with the board far from full
+        let board = StandardBoard::set_position(&[(27, Disc::Black), (28, Disc::Black)]).unwrap();
+        assert_eq!(board.count_discs(Disc::Black), 2);
+        assert_eq!(board.tournament_score(), (64, 0));
+
+        let board = StandardBoard::set_position(&[(0, Disc::White), (63, Disc::Black)]).unwrap();
+        assert_eq!(board.count_empty_squares(), 62);
+        assert_eq!(board.tournament_score(), (32, 32));
+
+        let board = Board::<4>::set_position(&[(0, Disc::White), (15, Disc::White)]).unwrap();
+        assert_eq!(board.tournament_score(), (0, 16));
+    }
+
+    #[test]
+    fn boards_as_hash_map_keys() {
+        use std::collections::HashMap;
+
+        let mut after_d3 = StandardBoard::new();
+        after_d3.apply_move(19, Disc::Black).unwrap();
+        let mut scores = HashMap::new();
+        scores.insert(StandardBoard::new(), 0);
+        scores.insert(after_d3.clone(), 3);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores.get(&StandardBoard::new()), Some(&0));
+        assert_eq!(scores.get(&after_d3), Some(&3));
+
+        // the same position reached another way is the same key
+        let mut transposed = StandardBoard::new();
+        transposed.set_field(19, Disc::Black).unwrap();
+        transposed.set_field(27, Disc::Black).unwrap();
+        scores.insert(transposed, 5);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[&after_d3], 5);
+        assert_eq!(scores.get(&StandardBoard::empty()), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn diff() {
+        let before = StandardBoard::new();
+        assert!(before.diff(&before).is_empty());
+
+        let mut after = before.clone();
+        after.apply_move(19, Disc::Black).unwrap();
+        assert_eq!(
+            before.diff(&after),
+            [
+                (19, None, Some(Disc::Black)),
+                (27, Some(Disc::White), Some(Disc::Black)),
+            ]
+        );
+
+        // in general, the placed square plus every flipped one
+        for board in random_boards::<8>(0xd1ff, 10) {
+            for index in board.valid_moves(Disc::White) {
+                let mut after = board.clone();
+                let flips = after
+                    .apply_move_returning_flips(index, Disc::White)
+                    .unwrap();
+                let mut expected: Vec<_> = flips
+                    .iter()
+                    .map(|&flip| (flip, Some(Disc::Black), Some(Disc::White)))
+                    .chain([(index, None, Some(Disc::White))])
+                    .collect();
+                expected.sort_by_key(|&(square, _, _)| square);
+                assert_eq!(board.diff(&after), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn apply_sequence() {
+        let moves = [(19, Disc::Black), (18, Disc::White), (17, Disc::Black)];
+        let mut board = StandardBoard::new();
+        assert_eq!(board.apply_sequence(&moves), Ok(()));
+        let mut expected = StandardBoard::new();
+        for (index, disc) in moves {
+            expected.apply_move(index, disc).unwrap();
+        }
+        assert_eq!(board, expected);
+        assert_eq!(board.apply_sequence(&[]), Ok(()));
+    }
+
+    #[test]
+    fn apply_sequence_stops_at_failure() {
+        let moves = [
+            (19, Disc::Black),
+            (18, Disc::White),
+            (19, Disc::Black),
+            (17, Disc::Black),
+        ];
+        let mut board = StandardBoard::new();
+        assert_eq!(
+            board.apply_sequence(&moves),
+            Err((2, BoardError::SquareOccupied))
+        );
+        let mut expected = StandardBoard::new();
+        expected.apply_sequence(&moves[..2]).unwrap();
+        assert_eq!(board, expected);
+
+        assert_eq!(
+            StandardBoard::new().apply_sequence(&[(0, Disc::Black)]),
+            Err((0, BoardError::InvalidMove))
+        );
+    }
+
+    #[test]
+    fn rank_and_file() {
+        let board = StandardBoard::new();
+        let (b, w) = (Some(Disc::Black), Some(Disc::White));
+        assert_eq!(
+            board.rank(3),
+            Ok([None, None, None, w, b, None, None, None])
+        );
+        assert_eq!(
+            board.rank(4),
+            Ok([None, None, None, b, w, None, None, None])
+        );
+        assert_eq!(
+            board.file(3),
+            Ok([None, None, None, w, b, None, None, None])
+        );
+        assert_eq!(
+            board.file(4),
+            Ok([None, None, None, b, w, None, None, None])
+        );
+        assert_eq!(board.rank(0), Ok([None; 8]));
+
+        assert_eq!(board.rank(8), Err(BoardError::OutOfBounds));
+        assert_eq!(board.file(8), Err(BoardError::OutOfBounds));
+        assert_eq!(
+            Board::<6>::new().rank(2),
+            Ok([None, None, w, b, None, None])
+        );
+    }
+
+    #[test]
+    fn default_is_opening() {
+        assert_eq!(StandardBoard::default(), StandardBoard::new());
+        assert_eq!(Board::<6>::default(), Board::<6>::new());
+
+        #[derive(Default)]
+        struct Holder {
+            board: StandardBoard,
+            to_move: Disc,
+        }
+        let holder = Holder::default();
+        assert_eq!(holder.board, StandardBoard::new());
+        assert_eq!(holder.to_move, Disc::Black);
+    }
 }
@@ -1,14 +1,68 @@
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// A player's color. Defaults to `Black`, the side that moves first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Disc {
+    #[default]
     Black,
     White
 }
 
 impl Disc {
+    /// Both colors, black first.
+    pub const BOTH: [Disc; 2] = [Disc::Black, Disc::White];
+
+    /// Iterates over `BOTH`.
+    pub fn iter() -> impl Iterator<Item = Disc> {
+        Self::BOTH.into_iter()
+    }
+
     pub fn opposite(&self) -> Disc {
         match self {
             Self::Black => Self::White,
             Self::White => Self::Black
         }
     }
+
+    /// `'B'` or `'W'`, as used by the text formats.
+    pub fn to_char(self) -> char {
+        match self {
+            Self::Black => 'B',
+            Self::White => 'W'
+        }
+    }
+
+    pub fn from_char(c: char) -> Option<Disc> {
+        match c {
+            'B' => Some(Self::Black),
+            'W' => Some(Self::White),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_round_trip() {
+        for disc in Disc::iter() {
+            assert_eq!(Disc::from_char(disc.to_char()), Some(disc));
+        }
+        for c in ['b', 'w', '-', '.', ' ', '○'] {
+            assert_eq!(Disc::from_char(c), None);
+        }
+    }
+
+    #[test]
+    fn iter_yields_both_once() {
+        assert_eq!(Disc::iter().collect::<Vec<_>>(), [Disc::Black, Disc::White]);
+        assert_eq!(Disc::BOTH, [Disc::Black, Disc::White]);
+        assert_eq!(Disc::iter().count(), 2);
+    }
+
+    #[test]
+    fn default_is_black() {
+        assert_eq!(Disc::default(), Disc::Black);
+    }
 }
@@ -0,0 +1,109 @@
+use crate::board::Board;
+use crate::disc::Disc;
+
+/// Scores a position from `disc`'s point of view; higher is better for `disc`.
+pub trait Evaluator {
+    fn score(&self, board: &Board, disc: Disc) -> i32;
+}
+
+/// Own discs minus opponent discs.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiscDifference;
+
+impl Evaluator for DiscDifference {
+    fn score(&self, board: &Board, disc: Disc) -> i32 {
+        board.count_discs(disc) as i32 - board.count_discs(disc.opposite()) as i32
+    }
+}
+
+/// Disc difference plus a bonus per corner held, minus a penalty per disc next to a
+/// corner that is still empty, since those discs tend to hand the corner over.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CornerWeighted;
+
+impl CornerWeighted {
+    pub const CORNER_BONUS: i32 = 25;
+    pub const NEAR_CORNER_PENALTY: i32 = 10;
+
+    const CORNERS: [(usize, [usize; 3]); 4] = [
+        (0, [1, 8, 9]),
+        (7, [6, 14, 15]),
+        (56, [48, 49, 57]),
+        (63, [54, 55, 62]),
+    ];
+}
+
+impl Evaluator for CornerWeighted {
+    fn score(&self, board: &Board, disc: Disc) -> i32 {
+        let sign = |index: usize| match board.get_field(index) {
+            Ok(Some(owner)) if owner == disc => 1,
+            Ok(Some(_)) => -1,
+            _ => 0,
+        };
+        let mut score = DiscDifference.score(board, disc);
+        for (corner, neighbors) in Self::CORNERS {
+            if let Ok(None) = board.get_field(corner) {
+                let exposed: i32 = neighbors.iter().map(|&i| sign(i)).sum();
+                score -= exposed * Self::NEAR_CORNER_PENALTY;
+            } else {
+                score += sign(corner) * Self::CORNER_BONUS;
+            }
+        }
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // black to move, with 39, 40, 47, 55 and the corner 63 each flipping two discs
+    const CORNER_AVAILABLE: [usize; 20] = [
+        19, 34, 44, 37, 30, 52, 43, 38, 41, 33, 45, 48, 61, 23, 42, 46, 25, 17, 54, 62,
+    ];
+
+    fn corner_position() -> Board {
+        let mut board = Board::new();
+        let mut disc = Disc::Black;
+        for &index in &CORNER_AVAILABLE {
+            board.apply_move(index, disc).unwrap();
+            disc = disc.opposite();
+        }
+        board
+    }
+
+    fn after(board: &Board, index: usize) -> Board {
+        let mut next = board.clone();
+        next.apply_move(index, Disc::Black).unwrap();
+        next
+    }
+
+    #[test]
+    fn disc_difference_is_zero_sum() {
+        let board = corner_position();
+        assert_eq!(DiscDifference.score(&Board::new(), Disc::Black), 0);
+        assert_eq!(
+            DiscDifference.score(&board, Disc::Black),
+            -DiscDifference.score(&board, Disc::White)
+        );
+    }
+
+    #[test]
+    fn corner_weighted_prefers_corner_capture() {
+        let board = corner_position();
+        let corner = after(&board, 63);
+        let edge = after(&board, 39);
+
+        assert_eq!(
+            DiscDifference.score(&corner, Disc::Black),
+            DiscDifference.score(&edge, Disc::Black)
+        );
+        assert!(
+            CornerWeighted.score(&corner, Disc::Black) > CornerWeighted.score(&edge, Disc::Black)
+        );
+        assert!(
+            CornerWeighted.score(&after(&board, 55), Disc::Black)
+                < CornerWeighted.score(&edge, Disc::Black)
+        );
+    }
+}
@@ -1,34 +1,147 @@
-use crate::board::{Board, BoardError};
+use crate::board::{Board, BoardError, StandardBoard, MAX_SURFACE};
 use crate::disc::Disc;
+use crate::evaluator::Evaluator;
 use crate::player::Player;
+use arrayvec::ArrayVec;
+use std::fmt;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GameError {
     InvalidMove,
     BoardError(BoardError),
+    /// The player went over its time limit and forfeited the game.
+    TimedOut,
+    /// There is no move left in the history to take back.
+    NothingToUndo,
+    /// The game has already ended, so no more turns can be played.
+    GameOver,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::InvalidMove => f.write_str("move is not legal"),
+            GameError::BoardError(err) => write!(f, "board error: {}", err),
+            GameError::TimedOut => f.write_str("player ran out of time"),
+            GameError::NothingToUndo => f.write_str("there is no move to undo"),
+            GameError::GameOver => f.write_str("the game is already over"),
+        }
+    }
+}
+
+impl From<BoardError> for GameError {
+    fn from(err: BoardError) -> Self {
+        GameError::BoardError(err)
+    }
+}
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GameError::BoardError(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameOutcome {
     Tie,
     Winner(Disc)
 }
 
-pub struct Game {
+impl GameOutcome {
+    pub fn winner(&self) -> Option<Disc> {
+        match *self {
+            GameOutcome::Tie => None,
+            GameOutcome::Winner(disc) => Some(disc),
+        }
+    }
+
+    pub fn is_tie(&self) -> bool {
+        *self == GameOutcome::Tie
+    }
+
+    /// The outcome as a score for `disc`: 1.0 for a win, 0.0 for a loss and 0.5 for a tie.
+    pub fn result_for(&self, disc: Disc) -> f32 {
+        match *self {
+            GameOutcome::Tie => 0.5,
+            GameOutcome::Winner(winner) if winner == disc => 1.0,
+            GameOutcome::Winner(_) => 0.0,
+        }
+    }
+}
+
+/// A single entry in a game's history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Turn {
+    Move { disc: Disc, index: usize },
+    Pass(Disc),
+}
+
+/// What happened during `Game::run_with_observer`, reported as it happens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GameEvent {
+    MoveMade {
+        disc: Disc,
+        index: usize,
+        flipped: Vec<usize>,
+    },
+    Passed(Disc),
+    GameEnded(Option<GameOutcome>),
+}
+
+/// What happens when a player takes longer than its `Player::time_limit`.
+///
+/// A player with a limit is asked for its move on a thread of its own, and the game
+/// stops waiting at the deadline: the policy applies and a late answer is thrown
+/// away, never played. Players may borrow from the caller, so the turn only ends
+/// once that thread has finished; a player should still give up soon after its limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeoutPolicy {
+    /// The late player loses the game, as in tournament play.
+    #[default]
+    Forfeit,
+    /// A legal move is played on the late player's behalf instead. The choice is a
+    /// fixed function of the position, so replays are reproducible.
+    FallbackMove,
+}
+
+/// The position and side to move of a `Game`, without its players or history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameSnapshot {
+    pub board: Board,
+    pub current: Disc,
+    /// The number of turns played when the snapshot was taken, like `Game::ply`.
+    pub ply: u32,
+}
+
+const PASS_TOKEN: &str = "pass";
+
+/// A game between two players. The players may borrow data for `'p`, so a game can
+/// be played between players owned elsewhere.
+pub struct Game<'p> {
     board: Board,
-    black: Box<dyn Player>,
-    white: Box<dyn Player>,
+    initial: Board,
+    black: Box<dyn Player + 'p>,
+    white: Box<dyn Player + 'p>,
     current: Disc,
+    history: Vec<Turn>,
+    /// The discs turned over by each history entry, empty for passes.
+    flipped: Vec<Vec<usize>>,
+    consecutive_passes: u8,
+    timeout_policy: TimeoutPolicy,
+    /// The side that conceded, by resigning or by running out of time.
+    resigned: Option<Disc>,
 }
 
-impl Game {
-    pub fn new(black: Box<dyn Player>, white: Box<dyn Player>) -> Self {
-        Game {
-            board: Board::new(),
-            black,
-            white,
-            current: Disc::Black,
-        }
+impl<'p> Game<'p> {
+    pub fn new(black: Box<dyn Player + 'p>, white: Box<dyn Player + 'p>) -> Self {
+        GameBuilder::new().build(black, white)
     }
 
     pub fn current_disc(&self) -> Disc {
@@ -42,83 +155,539 @@ impl Game {
         }
     }
 
+    /// The current player's legal moves, ascending and without duplicates like
+    /// `Board::valid_moves`.
     pub fn available_moves(&self) -> Vec<usize> {
         self.board.valid_moves(self.current).into_iter().collect()
     }
 
+    /// The current player's legal moves in algebraic notation, sorted.
+    pub fn available_moves_algebraic(&self) -> Vec<String> {
+        let mut moves: Vec<String> = self
+            .available_moves()
+            .into_iter()
+            .map(|index| {
+                StandardBoard::algebraic_from_index(index).expect("legal moves are on the board")
+            })
+            .collect();
+        moves.sort();
+        moves
+    }
+
     pub fn forced_pass(&self) -> bool {
         self.available_moves().is_empty()
     }
 
+    /// Plays `choice` for the current player and hands the turn over, for callers
+    /// driving the game without `step`. An illegal choice leaves the turn where it is.
+    pub fn play(&mut self, choice: usize) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        self.apply_current(choice)?;
+        self.advance_turn();
+        Ok(())
+    }
+
+    /// Passes for the current player and hands the turn over, for callers driving the
+    /// game without `step`. Only allowed when `forced_pass` holds; fails with
+    /// `GameError::InvalidMove` while a legal move exists and `GameError::GameOver`
+    /// once the game has ended.
+    pub fn pass(&mut self) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        if !self.forced_pass() {
+            return Err(GameError::InvalidMove);
+        }
+        self.pass_current();
+        self.advance_turn();
+        Ok(())
+    }
+
+    /// `disc`'s legal moves in the current position, whether or not it is `disc`'s turn.
+    pub fn legal_for(&self, disc: Disc) -> Vec<usize> {
+        self.board.valid_moves(disc).into_iter().collect()
+    }
+
+    /// Whether `disc` would have to pass if it were its turn.
+    pub fn must_pass(&self, disc: Disc) -> bool {
+        self.board.valid_moves(disc).is_empty()
+    }
+
+    /// The board as it would be after the current player plays `choice`.
+    /// The game itself is left as it is.
+    pub fn preview_move(&self, choice: usize) -> Result<Board, GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        if !self.board.is_valid_move(choice, self.current) {
+            return Err(GameError::InvalidMove);
+        }
+        let mut board = self.board.clone();
+        board.apply_move(choice, self.current)?;
+        Ok(board)
+    }
+
     fn apply_current(&mut self, choice: usize) -> Result<(), GameError> {
+        self.play_current(choice).map(|_| ())
+    }
+
+    fn play_current(&mut self, choice: usize) -> Result<ArrayVec<usize, MAX_SURFACE>, GameError> {
         let legal = self.board.valid_moves(self.current);
         if !legal.contains(&choice) {
             return Err(GameError::InvalidMove);
         }
-        self.board
-            .apply_move(choice, self.current)
-            .map_err(GameError::BoardError)?;
-        Ok(())
+        let flips = self.board.apply_move_returning_flips(choice, self.current)?;
+        self.history.push(Turn::Move {
+            disc: self.current,
+            index: choice,
+        });
+        self.flipped.push(flips.to_vec());
+        self.consecutive_passes = 0;
+        Ok(flips)
+    }
+
+    fn pass_current(&mut self) {
+        self.history.push(Turn::Pass(self.current));
+        self.flipped.push(Vec::new());
+        self.consecutive_passes = self.consecutive_passes.saturating_add(1);
     }
 
     fn advance_turn(&mut self) {
         self.current = self.current.opposite();
     }
 
+    /// Whether the game has ended: a side conceded, or neither side has a legal move.
     pub fn is_over(&self) -> bool {
-        self.board.valid_moves(Disc::Black).is_empty() && self.board.valid_moves(Disc::White).is_empty()
+        self.resigned.is_some() || !self.board.any_moves()
+    }
+
+    /// `eval`'s score for the current position, from the side to move's point of view.
+    pub fn evaluate<E: Evaluator>(&self, eval: &E) -> i32 {
+        eval.score(&self.board, self.current)
+    }
+
+    /// Live disc counts as `(black, white)`.
+    pub fn current_score(&self) -> (usize, usize) {
+        let (black, white, _) = self.board.disc_counts();
+        (black, white)
+    }
+
+    /// The disc currently ahead on the board, or `None` when level.
+    pub fn leader(&self) -> Option<Disc> {
+        let (b, w) = self.current_score();
+        match b.cmp(&w) {
+            std::cmp::Ordering::Greater => Some(Disc::Black),
+            std::cmp::Ordering::Less => Some(Disc::White),
+            std::cmp::Ordering::Equal => None,
+        }
     }
 
     pub fn outcome(&self) -> Option<GameOutcome> {
         if !self.is_over() {
             return None;
         }
-        let b = self.board.count_discs(Disc::Black);
-        let w = self.board.count_discs(Disc::White);
-        match b.cmp(&w) {
-            std::cmp::Ordering::Greater => Some(GameOutcome::Winner(Disc::Black)),
-            std::cmp::Ordering::Less => Some(GameOutcome::Winner(Disc::White)),
-            std::cmp::Ordering::Equal => Some(GameOutcome::Tie),
+        if let Some(loser) = self.resigned {
+            return Some(GameOutcome::Winner(loser.opposite()));
         }
+        Some(self.leader().map_or(GameOutcome::Tie, GameOutcome::Winner))
     }
 
-    pub fn run(&mut self) -> Option<GameOutcome> {
-        while !self.is_over() {
-            if self.forced_pass() {
-                self.advance_turn();
-            } else {
-                let player = self.current_player();
-                let choice = player.select_move(&self.board, self.current);
-                if self.board.is_valid_move(choice, self.current) {
-                    let _ = self.apply_current(choice);
-                    self.advance_turn();
+    /// A status panel for spectators: the board with coordinates, the score, and whose
+    /// turn it is, noting a forced pass. Once the game is over, the result replaces the turn.
+    pub fn render(&self) -> String {
+        let (black, white) = self.current_score();
+        let status = match self.outcome() {
+            Some(GameOutcome::Winner(disc)) => format!("Game over: {:?} wins", disc),
+            Some(GameOutcome::Tie) => "Game over: tie".to_string(),
+            None if self.forced_pass() => format!("{:?} to move (must pass)", self.current_disc()),
+            None => format!("{:?} to move", self.current_disc()),
+        };
+        format!(
+            "{}Black {} - {} White\n{}\n",
+            self.board.display_with_coordinates(),
+            black,
+            white,
+            status
+        )
+    }
+
+    /// Whether the game is over with every square filled. `false` while the game is
+    /// going, and for games that ended with both sides stuck or with a concession.
+    pub fn ended_full(&self) -> bool {
+        self.is_over() && self.board.is_full()
+    }
+
+    /// The winner's final lead in discs, scored like `Board::tournament_score` so
+    /// empty squares left on a finished board count for the winner, and zero for a
+    /// tie. `None` while the game is still going, and for a game decided by a
+    /// resignation or a timeout, whose result doesn't come from the board.
+    pub fn margin(&self) -> Option<usize> {
+        if !self.is_over() || self.resigned.is_some() {
+            return None;
+        }
+        let (black, white) = self.board.tournament_score();
+        Some(black.abs_diff(white))
+    }
+
+    /// Asks the current player for a move and plays it, or passes if the player returns `None`.
+    /// An illegal choice, or a pass while a legal move exists, fails with
+    /// `GameError::InvalidMove` and leaves the turn with the same player.
+    /// Fails with `GameError::GameOver` once the game has ended.
+    pub fn step(&mut self) -> Result<Turn, GameError> {
+        self.step_event().map(|event| match event {
+            GameEvent::MoveMade { disc, index, .. } => Turn::Move { disc, index },
+            GameEvent::Passed(disc) => Turn::Pass(disc),
+            GameEvent::GameEnded(_) => unreachable!("a single step never ends the game"),
+        })
+    }
+
+    fn step_event(&mut self) -> Result<GameEvent, GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        let disc = self.current;
+        let choice = match self.select_in_time(disc) {
+            Ok(choice) => choice,
+            Err(err) => match self.timeout_policy {
+                TimeoutPolicy::Forfeit => {
+                    self.resigned = Some(disc);
+                    return Err(err);
                 }
+                TimeoutPolicy::FallbackMove => self.fallback_move(),
+            },
+        };
+        let event = match choice {
+            Some(index) => {
+                let flipped = self.play_current(index)?.to_vec();
+                GameEvent::MoveMade {
+                    disc,
+                    index,
+                    flipped,
+                }
+            }
+            None => {
+                if !self.forced_pass() {
+                    return Err(GameError::InvalidMove);
+                }
+                self.pass_current();
+                GameEvent::Passed(disc)
+            }
+        };
+        self.advance_turn();
+        Ok(event)
+    }
+
+    /// Asks `disc`'s player for its move. A player with a time limit is asked on a
+    /// scoped thread, and if its answer hasn't arrived by the deadline this fails with
+    /// `GameError::TimedOut` and the answer is ignored.
+    fn select_in_time(&mut self, disc: Disc) -> Result<Option<usize>, GameError> {
+        let player = match disc {
+            Disc::Black => &mut self.black,
+            Disc::White => &mut self.white,
+        };
+        let board = &self.board;
+        let Some(limit) = player.time_limit() else {
+            return Ok(player.select_move(board, disc));
+        };
+        let deadline = Instant::now() + limit;
+        let (answer, answered) = mpsc::channel();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                let _ = answer.send(player.select_move(board, disc));
+            });
+            match answered.recv_timeout(limit) {
+                Ok(choice) if Instant::now() < deadline => Ok(choice),
+                _ => Err(GameError::TimedOut),
+            }
+        })
+    }
+
+    /// Ends the game with `disc` conceding, whoever's turn it is, and returns the result.
+    /// A game that is already over keeps its outcome.
+    pub fn resign(&mut self, disc: Disc) -> GameOutcome {
+        if let Some(outcome) = self.outcome() {
+            return outcome;
+        }
+        self.resigned = Some(disc);
+        GameOutcome::Winner(disc.opposite())
+    }
+
+    /// Ends the game with the player to move conceding, as when a human quits.
+    /// A game that is already over keeps its outcome.
+    pub fn concede_current(&mut self) {
+        self.resign(self.current);
+    }
+
+    /// Passes in a row since the last move, reset to zero by any move.
+    pub fn consecutive_passes(&self) -> u8 {
+        self.consecutive_passes
+    }
+
+    /// The move played for a late player under `TimeoutPolicy::FallbackMove`: one of
+    /// the legal moves, picked by the position's hash.
+    fn fallback_move(&self) -> Option<usize> {
+        let moves = self.board.valid_moves(self.current);
+        if moves.is_empty() {
+            return None;
+        }
+        Some(moves[(self.board.zobrist_hash() % moves.len() as u64) as usize])
+    }
+
+    pub fn timeout_policy(&self) -> TimeoutPolicy {
+        self.timeout_policy
+    }
+
+    pub fn set_timeout_policy(&mut self, policy: TimeoutPolicy) {
+        self.timeout_policy = policy;
+    }
+
+    /// Plays the game out and returns its outcome. Stops with the error if a player's
+    /// choice is rejected, leaving the turn with that player; running out of time is
+    /// not an error, since the timeout policy decides the game.
+    pub fn run(&mut self) -> Result<GameOutcome, GameError> {
+        self.run_with_observer(|_| {})
+    }
+
+    /// Plays the game out like `run`, calling `observer` after every move and pass
+    /// and once more when the game ends.
+    pub fn run_with_observer<F: FnMut(&GameEvent)>(
+        &mut self,
+        mut observer: F,
+    ) -> Result<GameOutcome, GameError> {
+        while !self.is_over() {
+            match self.step_event() {
+                Ok(event) => observer(&event),
+                Err(GameError::TimedOut) => {}
+                Err(err) => return Err(err),
             }
         }
-        self.outcome()
+        let outcome = self.outcome().expect("the game is over");
+        observer(&GameEvent::GameEnded(Some(outcome)));
+        Ok(outcome)
     }
 
     pub fn board(&self) -> &Board {
         &self.board
     }
+
+    /// Replaces the position mid-game, for tests and scenario setup. The new board
+    /// becomes the starting point: the history is cleared so undo and `board_at`
+    /// stay consistent with it, and any resignation is dropped. The side to move is
+    /// unchanged.
+    pub fn set_board(&mut self, board: Board) {
+        self.initial = board.clone();
+        self.board = board;
+        self.history.clear();
+        self.flipped.clear();
+        self.consecutive_passes = 0;
+        self.resigned = None;
+    }
+
+    /// The current position and side to move, to come back to with `restore`.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            board: self.board.clone(),
+            current: self.current,
+            ply: self.ply(),
+        }
+    }
+
+    /// Returns to `snap`, for example after a rollout played from it. The turns played
+    /// since the snapshot are taken back, and any resignation is dropped, so the
+    /// history, `ply`, `board_at` and undo carry on from there. If the history no
+    /// longer leads back to `snap`, say after `set_board`, the game restarts at `snap`
+    /// as with `set_board`.
+    pub fn restore(&mut self, snap: GameSnapshot) {
+        self.resigned = None;
+        while self.ply() > snap.ply {
+            self.undo_last()
+                .expect("turns can be taken back once the resignation is dropped");
+        }
+        if self.ply() != snap.ply || self.board != snap.board {
+            self.set_board(snap.board);
+        }
+        self.current = snap.current;
+    }
+
+    /// Starts a rematch with colors reversed: the black and white players trade places
+    /// and the game restarts from the opening with Black to move.
+    pub fn swap_players(&mut self) {
+        std::mem::swap(&mut self.black, &mut self.white);
+        self.set_board(Board::new());
+        self.current = Disc::Black;
+    }
+
+    /// Hands the turn to `disc`, for tests and scenario setup.
+    pub fn set_current(&mut self, disc: Disc) {
+        self.current = disc;
+    }
+
+    pub fn history(&self) -> &[Turn] {
+        &self.history
+    }
+
+    /// Turns played so far, passes included. Always the length of the history.
+    pub fn ply(&self) -> u32 {
+        self.history.len() as u32
+    }
+
+    /// The move number shown to players, starting at 1 and advancing every two plies.
+    pub fn move_number(&self) -> u32 {
+        self.ply() / 2 + 1
+    }
+
+    /// The history as space-separated algebraic moves, with `pass` for each pass.
+    /// Only the turns are written, not the starting position; see `from_transcript`.
+    pub fn to_transcript(&self) -> String {
+        self.history
+            .iter()
+            .map(|turn| match *turn {
+                Turn::Move { index, .. } => StandardBoard::algebraic_from_index(index)
+                    .expect("played moves are on the board"),
+                Turn::Pass(_) => PASS_TOKEN.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Replays a transcript written by `to_transcript` from the standard opening with
+    /// Black to move, checking every turn like `play` and `pass` do: a pass is only
+    /// accepted when the side to move has no legal move, and any token after the game
+    /// has ended fails with `GameError::GameOver`.
+    ///
+    /// Transcripts don't record where the game started, so one written by a game set
+    /// up with `GameBuilder` or `set_board` can't be replayed this way unless that
+    /// game also started from the opening with Black to move.
+    pub fn from_transcript(
+        black: Box<dyn Player + 'p>,
+        white: Box<dyn Player + 'p>,
+        transcript: &str,
+    ) -> Result<Game<'p>, GameError> {
+        let mut game = Game::new(black, white);
+        for token in transcript.split_whitespace() {
+            if game.is_over() {
+                return Err(GameError::GameOver);
+            }
+            if token == PASS_TOKEN {
+                game.pass()?;
+            } else {
+                game.play(StandardBoard::index_from_algebraic(token)?)?;
+            }
+        }
+        Ok(game)
+    }
+
+    /// The board as it stood after the first `ply` entries of the history.
+    pub fn board_at(&self, ply: usize) -> Option<Board> {
+        let mut board = self.initial.clone();
+        for turn in self.history.get(..ply)? {
+            if let Turn::Move { disc, index } = *turn {
+                board.apply_move(index, disc).ok()?;
+            }
+        }
+        Some(board)
+    }
+
+    /// Takes back the most recent move or pass, handing the turn back to whoever made it.
+    /// Fails with `GameError::GameOver` once a side has conceded, by resigning or running
+    /// out of time, since that result does not come from the moves played.
+    pub fn undo_last(&mut self) -> Result<(), GameError> {
+        if self.resigned.is_some() {
+            return Err(GameError::GameOver);
+        }
+        let last = self.history.pop().ok_or(GameError::NothingToUndo)?;
+        let flipped = self.flipped.pop().expect("every turn records its flips");
+        self.current = match last {
+            Turn::Move { disc, index } => {
+                self.board.undo_move(index, disc, &flipped)?;
+                disc
+            }
+            Turn::Pass(disc) => disc,
+        };
+        let trailing_passes = self
+            .history
+            .iter()
+            .rev()
+            .take_while(|turn| matches!(turn, Turn::Pass(_)))
+            .count();
+        self.consecutive_passes = trailing_passes.min(u8::MAX as usize) as u8;
+        Ok(())
+    }
+}
+
+/// Sets up a `Game` from a position other than the standard opening.
+#[derive(Clone, Debug, Default)]
+pub struct GameBuilder {
+    board: Board,
+    current: Option<Disc>,
+    timeout_policy: TimeoutPolicy,
+}
+
+impl GameBuilder {
+    pub fn new() -> Self {
+        GameBuilder::default()
+    }
+
+    /// The side to move first. Black by default.
+    pub fn starting_disc(mut self, disc: Disc) -> Self {
+        self.current = Some(disc);
+        self
+    }
+
+    /// The position to start from; its dimensions are fixed by the `Board` type.
+    pub fn initial_board(mut self, board: Board) -> Self {
+        self.board = board;
+        self
+    }
+
+    pub fn timeout_policy(mut self, policy: TimeoutPolicy) -> Self {
+        self.timeout_policy = policy;
+        self
+    }
+
+    pub fn build<'p>(self, black: Box<dyn Player + 'p>, white: Box<dyn Player + 'p>) -> Game<'p> {
+        Game {
+            board: self.board.clone(),
+            initial: self.board,
+            black,
+            white,
+            current: self.current.unwrap_or(Disc::Black),
+            history: Vec::new(),
+            flipped: Vec::new(),
+            consecutive_passes: 0,
+            timeout_policy: self.timeout_policy,
+            resigned: None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evaluator::DiscDifference;
     use crate::player::Player;
+    use std::time::Duration;
 
     struct DummyPlayer;
     impl Player for DummyPlayer {
-        fn select_move(&self, _board: &Board, _disc: Disc) -> usize {
-            0
+        fn select_move(&self, _board: &Board, _disc: Disc) -> Option<usize> {
+            Some(0)
+        }
+    }
+
+    struct PassingPlayer;
+    impl Player for PassingPlayer {
+        fn select_move(&self, _board: &Board, _disc: Disc) -> Option<usize> {
+            None
         }
     }
 
     struct ValidPlayer;
     impl Player for ValidPlayer {
-        fn select_move(&self, board: &Board, disc: Disc) -> usize {
-            board.valid_moves(disc)[0]
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            board.valid_moves(disc).first().copied()
         }
     }
 
@@ -201,10 +770,851 @@ mod tests {
             Box::new(ValidPlayer),
             Box::new(ValidPlayer),
         );
-        let winner = game.run();
+        assert!(game.run().is_ok());
         assert!(game.is_over());
-        assert!(winner.is_some());
         assert!(game.board().valid_moves(Disc::Black).is_empty());
         assert!(game.board().valid_moves(Disc::White).is_empty());
     }
+
+    #[test]
+    fn test_run_rejects_voluntary_pass() {
+        let mut game = Game::new(
+            Box::new(PassingPlayer),
+            Box::new(PassingPlayer),
+        );
+        assert_eq!(game.run(), Err(GameError::InvalidMove));
+        assert!(game.history().is_empty());
+        assert_eq!(game.consecutive_passes(), 0);
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn test_run_returns_illegal_move() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(DummyPlayer),
+        );
+        assert_eq!(game.run(), Err(GameError::InvalidMove));
+        assert_eq!(game.history(), [Turn::Move { disc: Disc::Black, index: 19 }]);
+        assert_eq!(game.current_disc(), Disc::White);
+        assert!(!game.is_over());
+    }
+
+    #[test]
+    fn test_history_and_board_at() {
+        let moves = [19, 18, 17];
+        let mut game = Game::new(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+        );
+        for &mv in &moves {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert_eq!(
+            game.history(),
+            &[
+                Turn::Move { disc: Disc::Black, index: 19 },
+                Turn::Move { disc: Disc::White, index: 18 },
+                Turn::Move { disc: Disc::Black, index: 17 },
+            ]
+        );
+
+        let mut expected = Board::new();
+        assert_eq!(game.board_at(0), Some(expected.clone()));
+        expected.apply_move(19, Disc::Black).unwrap();
+        assert_eq!(game.board_at(1), Some(expected));
+        assert_eq!(game.board_at(3).as_ref(), Some(game.board()));
+        assert_eq!(game.board_at(4), None);
+    }
+
+    #[test]
+    fn test_step_forced_pass_through_player() {
+        let moves = [19, 18, 17, 9, 37, 16, 0, 2];
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        for &mv in &moves {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert!(game.forced_pass());
+        assert_eq!(game.step(), Ok(Turn::Pass(Disc::Black)));
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.history().last(), Some(&Turn::Pass(Disc::Black)));
+        assert!(matches!(game.step(), Ok(Turn::Move { disc: Disc::White, .. })));
+    }
+
+    #[test]
+    fn test_consecutive_passes() {
+        let moves = [19, 18, 17, 9, 37, 16, 0, 2];
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        for &mv in &moves {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert_eq!(game.consecutive_passes(), 0);
+        assert_eq!(game.step(), Ok(Turn::Pass(Disc::Black)));
+        assert_eq!(game.consecutive_passes(), 1);
+        assert!(matches!(game.step(), Ok(Turn::Move { .. })));
+        assert_eq!(game.consecutive_passes(), 0);
+    }
+
+    #[test]
+    fn test_step_voluntary_pass_and_illegal_move() {
+        let mut game = Game::new(
+            Box::new(PassingPlayer),
+            Box::new(DummyPlayer),
+        );
+        // black has legal moves, so it may not pass
+        assert_eq!(game.step(), Err(GameError::InvalidMove));
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert!(game.history().is_empty());
+        assert_eq!(game.consecutive_passes(), 0);
+
+        assert_eq!(game.play(19), Ok(()));
+        let board = game.board().clone();
+        assert_eq!(game.step(), Err(GameError::InvalidMove));
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.board(), &board);
+    }
+
+    #[test]
+    fn test_current_score_and_leader() {
+        let mut game = Game::new(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+        );
+        assert_eq!(game.current_score(), (2, 2));
+        assert_eq!(game.leader(), None);
+
+        // (square_to_play, expected_black, expected_white, expected_leader)
+        let moves = [
+            (19, 4, 1, Some(Disc::Black)),
+            (18, 3, 3, None),
+            (17, 5, 2, Some(Disc::Black)),
+            (29, 4, 4, None),
+            (37, 7, 2, Some(Disc::Black)),
+        ];
+        for &(mv, black, white, leader) in &moves {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+            assert_eq!(game.current_score(), (black, white));
+            assert_eq!(game.leader(), leader);
+        }
+    }
+
+    #[test]
+    fn test_available_moves_algebraic() {
+        let game = Game::new(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+        );
+        let expected: Vec<String> = game
+            .available_moves()
+            .into_iter()
+            .map(|index| StandardBoard::algebraic_from_index(index).unwrap())
+            .collect();
+        assert_eq!(game.available_moves_algebraic(), ["c4", "d3", "e6", "f5"]);
+        assert_eq!(expected, ["d3", "c4", "f5", "e6"]);
+    }
+
+    #[test]
+    fn test_game_builder() {
+        let mut board = Board::new();
+        board.apply_move(19, Disc::Black).unwrap();
+        let mut game = GameBuilder::new()
+            .initial_board(board.clone())
+            .starting_disc(Disc::White)
+            .build(Box::new(ValidPlayer), Box::new(ValidPlayer));
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.board(), &board);
+
+        assert!(matches!(game.step(), Ok(Turn::Move { disc: Disc::White, .. })));
+        assert_eq!(game.board_at(0), Some(board));
+
+        let game = GameBuilder::new().build(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+        );
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert_eq!(game.board(), &Board::new());
+    }
+
+    #[test]
+    fn test_margin() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.margin(), None);
+        assert_eq!(game.run(), Ok(GameOutcome::Winner(Disc::White)));
+        assert_eq!(game.board().count_empty_squares(), 0);
+        assert_eq!(game.current_score(), (19, 45));
+        assert_eq!(game.margin(), Some(26));
+
+        // black wipes white out with 51 squares still empty, which all go to black
+        let moves = [44, 29, 20, 45, 38, 43, 52, 37, 34];
+        let mut game = Game::new(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+        );
+        for &mv in &moves {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert_eq!(game.current_score(), (13, 0));
+        assert_eq!(game.margin(), Some(64));
+        let (black, white) = game.board().tournament_score();
+        assert_eq!(game.margin(), Some(black - white));
+
+        // a resignation decides the game without a margin, even for a winner behind on discs
+        let mut game = Game::from_transcript(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+            "d3",
+        )
+        .unwrap();
+        assert_eq!(game.leader(), Some(Disc::Black));
+        assert_eq!(game.resign(Disc::Black), GameOutcome::Winner(Disc::White));
+        assert_eq!(game.margin(), None);
+    }
+
+    #[test]
+    fn test_transcript_round_trip() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        for &mv in &[19, 18, 17, 9, 37, 16, 0, 2] {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert_eq!(game.step(), Ok(Turn::Pass(Disc::Black)));
+        game.run().unwrap();
+
+        let transcript = game.to_transcript();
+        assert!(transcript.starts_with("d3 c3 b3 b2 f5 a3 a1 c1 pass "));
+        let replayed = Game::from_transcript(
+            Box::new(DummyPlayer),
+            Box::new(DummyPlayer),
+            &transcript,
+        )
+        .unwrap();
+        assert_eq!(replayed.board(), game.board());
+        assert_eq!(replayed.history(), game.history());
+        assert_eq!(replayed.outcome(), game.outcome());
+    }
+
+    #[test]
+    fn test_transcript_errors() {
+        let replay = |transcript: &str| {
+            Game::from_transcript(
+                Box::new(DummyPlayer),
+                Box::new(DummyPlayer),
+                transcript,
+            )
+            .map(|game| game.history().len())
+        };
+        assert_eq!(replay(""), Ok(0));
+        assert_eq!(replay("d3 c3"), Ok(2));
+        assert_eq!(replay("d3 d3"), Err(GameError::InvalidMove));
+        assert_eq!(replay("a1"), Err(GameError::InvalidMove));
+        assert_eq!(
+            replay("d3 z9"),
+            Err(GameError::BoardError(BoardError::OutOfBounds))
+        );
+        assert_eq!(
+            replay("d3 3d"),
+            Err(GameError::BoardError(BoardError::ParseError))
+        );
+    }
+
+    #[test]
+    fn test_transcript_rejects_illegal_pass() {
+        let replay = |transcript: &str| {
+            Game::from_transcript(
+                Box::new(DummyPlayer),
+                Box::new(DummyPlayer),
+                transcript,
+            )
+            .map(|game| game.history().len())
+        };
+        assert_eq!(replay("pass"), Err(GameError::InvalidMove));
+        assert_eq!(replay("pass pass d3"), Err(GameError::InvalidMove));
+        assert_eq!(replay("d3 pass"), Err(GameError::InvalidMove));
+        // black is shut out here, so its pass is forced
+        assert_eq!(replay("d3 c3 b3 b2 f5 a3 a1 c1 pass"), Ok(9));
+
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        game.run().unwrap();
+        let finished = game.to_transcript();
+        assert_eq!(replay(&finished), Ok(game.history().len()));
+        assert_eq!(replay(&format!("{} pass", finished)), Err(GameError::GameOver));
+        assert_eq!(replay(&format!("{} a1", finished)), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn test_run_with_observer() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        let mut events = Vec::new();
+        let outcome = game
+            .run_with_observer(|event| events.push(event.clone()))
+            .unwrap();
+
+        assert_eq!(
+            events.first(),
+            Some(&GameEvent::MoveMade {
+                disc: Disc::Black,
+                index: 19,
+                flipped: vec![27],
+            })
+        );
+        assert_eq!(events.last(), Some(&GameEvent::GameEnded(Some(outcome))));
+        assert_eq!(events.len(), game.history().len() + 1);
+
+        let moves = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::MoveMade { .. }))
+            .count();
+        let passes = events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::Passed(_)))
+            .count();
+        assert_eq!(moves + passes, game.history().len());
+        // each move places exactly one new disc
+        let (black, white) = game.current_score();
+        assert_eq!(moves, black + white - 4);
+    }
+
+    const LIMIT: Duration = Duration::from_millis(5);
+
+    /// Blocks on a channel that never delivers until its whole time limit has run
+    /// out, so its answer always arrives after the game's deadline.
+    struct LatePlayer {
+        _wake: mpsc::Sender<()>,
+        wait: mpsc::Receiver<()>,
+    }
+
+    impl LatePlayer {
+        fn new() -> Self {
+            let (wake, wait) = mpsc::channel();
+            LatePlayer { _wake: wake, wait }
+        }
+    }
+
+    impl Player for LatePlayer {
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            assert!(self.wait.recv_timeout(LIMIT).is_err());
+            board.valid_moves(disc).last().copied()
+        }
+
+        fn time_limit(&self) -> Option<Duration> {
+            Some(LIMIT)
+        }
+    }
+
+    /// Answers straight away, well within a generous limit.
+    struct PromptPlayer;
+    impl Player for PromptPlayer {
+        fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+            board.valid_moves(disc).first().copied()
+        }
+
+        fn time_limit(&self) -> Option<Duration> {
+            Some(Duration::from_secs(60))
+        }
+    }
+
+    #[test]
+    fn test_time_limit_met() {
+        let mut game = Game::new(
+            Box::new(PromptPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.step(), Ok(Turn::Move { disc: Disc::Black, index: 19 }));
+        assert_eq!(game.run(), Game::new(Box::new(ValidPlayer), Box::new(ValidPlayer)).run());
+    }
+
+    #[test]
+    fn test_timeout_forfeits() {
+        let mut game = Game::new(
+            Box::new(LatePlayer::new()),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.timeout_policy(), TimeoutPolicy::Forfeit);
+        assert_eq!(game.step(), Err(GameError::TimedOut));
+        assert!(game.is_over());
+        assert_eq!(game.board(), &Board::new());
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::White)));
+        assert_eq!(game.run(), Ok(GameOutcome::Winner(Disc::White)));
+    }
+
+    #[test]
+    fn test_timeout_fallback_move() {
+        let mut game = GameBuilder::new()
+            .timeout_policy(TimeoutPolicy::FallbackMove)
+            .build(Box::new(LatePlayer::new()), Box::new(ValidPlayer));
+        let turn = game.step().unwrap();
+        let Turn::Move { disc, index } = turn else {
+            panic!("expected a move, got {:?}", turn);
+        };
+        assert_eq!(disc, Disc::Black);
+        assert!(StandardBoard::new().is_valid_move(index, Disc::Black));
+        assert_eq!(game.current_disc(), Disc::White);
+        assert!(!game.is_over());
+
+        // the fallback depends only on the position, so it repeats
+        let mut again = GameBuilder::new()
+            .timeout_policy(TimeoutPolicy::FallbackMove)
+            .build(Box::new(LatePlayer::new()), Box::new(ValidPlayer));
+        assert_eq!(again.step(), Ok(turn));
+    }
+
+    #[test]
+    fn test_outcome_accessors() {
+        assert_eq!(GameOutcome::Tie.winner(), None);
+        assert!(GameOutcome::Tie.is_tie());
+        for disc in Disc::BOTH {
+            assert_eq!(GameOutcome::Winner(disc).winner(), Some(disc));
+            assert!(!GameOutcome::Winner(disc).is_tie());
+        }
+    }
+
+    #[test]
+    fn test_result_for() {
+        for disc in Disc::BOTH {
+            assert_eq!(GameOutcome::Winner(disc).result_for(disc), 1.0);
+            assert_eq!(GameOutcome::Winner(disc).result_for(disc.opposite()), 0.0);
+            assert_eq!(GameOutcome::Tie.result_for(disc), 0.5);
+        }
+    }
+
+    #[test]
+    fn test_undo_last() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.undo_last(), Err(GameError::NothingToUndo));
+
+        assert!(game.step().is_ok());
+        let before = game.board().clone();
+        assert_eq!(game.step(), Ok(Turn::Move { disc: Disc::White, index: 18 }));
+        assert_ne!(game.board(), &before);
+
+        assert_eq!(game.undo_last(), Ok(()));
+        assert_eq!(game.board(), &before);
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.history().len(), 1);
+
+        assert_eq!(game.undo_last(), Ok(()));
+        assert_eq!(game.board(), &Board::new());
+        assert_eq!(game.current_disc(), Disc::Black);
+    }
+
+    #[test]
+    fn test_undo_last_reverses_flips() {
+        let mut game = Game::from_transcript(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+            "d3 c3 b3 b2 f5 a3 a1",
+        )
+        .unwrap();
+        let before = game.board().clone();
+        let turn = game.step().unwrap();
+        assert_eq!(turn, Turn::Move { disc: Disc::White, index: 1 });
+        assert!(game.board().count_discs(Disc::White) > before.count_discs(Disc::White) + 1);
+
+        assert_eq!(game.undo_last(), Ok(()));
+        assert_eq!(game.board(), &before);
+        assert_eq!(game.board().zobrist_hash(), before.zobrist_hash());
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.step(), Ok(turn));
+    }
+
+    #[test]
+    fn test_legal_for_both_players() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.legal_for(Disc::Black), [19, 26, 37, 44]);
+        assert_eq!(game.legal_for(Disc::White), [20, 29, 34, 43]);
+        assert_eq!(game.legal_for(Disc::Black), game.available_moves());
+        assert!(!game.must_pass(Disc::Black));
+        assert!(!game.must_pass(Disc::White));
+
+        game.run().unwrap();
+        assert!(game.legal_for(Disc::Black).is_empty());
+        assert!(game.must_pass(Disc::Black));
+        assert!(game.must_pass(Disc::White));
+    }
+
+    #[test]
+    fn test_resign() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        game.step().unwrap();
+        game.step().unwrap();
+        assert!(!game.is_over());
+
+        assert_eq!(game.resign(Disc::Black), GameOutcome::Winner(Disc::White));
+        assert!(game.is_over());
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::White)));
+        assert_eq!(game.step(), Err(GameError::GameOver));
+        assert_eq!(game.history().len(), 2);
+
+        // the first result stands
+        assert_eq!(game.resign(Disc::White), GameOutcome::Winner(Disc::White));
+
+        // the moves before a resignation can't be taken back
+        assert_eq!(game.undo_last(), Err(GameError::GameOver));
+        assert_eq!(game.history().len(), 2);
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn test_concede_current() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        game.step().unwrap();
+        game.step().unwrap();
+        assert_eq!(game.current_disc(), Disc::Black);
+        game.concede_current();
+        assert!(game.is_over());
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::White)));
+    }
+
+    #[test]
+    fn test_step_after_game_end() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        let outcome = game.run().unwrap();
+        assert_eq!(game.step(), Err(GameError::GameOver));
+        assert_eq!(game.resign(outcome.winner().unwrap_or(Disc::Black)), outcome);
+    }
+
+    #[test]
+    fn test_ply_and_move_number() {
+        let mut game = Game::from_transcript(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+            "d3 c3 b3",
+        )
+        .unwrap();
+        assert_eq!(game.ply(), 3);
+        assert_eq!(game.move_number(), 2);
+
+        game.step().unwrap();
+        assert_eq!(game.ply(), 4);
+        assert_eq!(game.move_number(), 3);
+
+        // passes count as plies too; black is shut out after these eight moves
+        let mut game = Game::from_transcript(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+            "d3 c3 b3 b2 f5 a3 a1 c1",
+        )
+        .unwrap();
+        assert_eq!((game.ply(), game.move_number()), (8, 5));
+        assert_eq!(game.step(), Ok(Turn::Pass(Disc::Black)));
+        assert_eq!((game.ply(), game.move_number()), (9, 5));
+        game.undo_last().unwrap();
+        assert_eq!(game.ply(), 8);
+    }
+
+    #[test]
+    fn test_game_error_display() {
+        assert_eq!(GameError::InvalidMove.to_string(), "move is not legal");
+        assert_eq!(
+            GameError::BoardError(BoardError::SquareOccupied).to_string(),
+            "board error: square already occupied"
+        );
+        assert_eq!(GameError::TimedOut.to_string(), "player ran out of time");
+        assert_eq!(GameError::NothingToUndo.to_string(), "there is no move to undo");
+        assert_eq!(GameError::GameOver.to_string(), "the game is already over");
+
+        let boxed: Box<dyn std::error::Error> =
+            Box::new(GameError::BoardError(BoardError::OutOfBounds));
+        assert_eq!(boxed.to_string(), "board error: square is off the board");
+        assert_eq!(
+            boxed.source().map(|source| source.to_string()),
+            Some("square is off the board".to_string())
+        );
+    }
+
+    #[test]
+    fn test_game_error_from_board_error() {
+        assert_eq!(
+            GameError::from(BoardError::OutOfBounds),
+            GameError::BoardError(BoardError::OutOfBounds)
+        );
+        let err: GameError = BoardError::OutOfBounds.into();
+        assert_eq!(err, GameError::BoardError(BoardError::OutOfBounds));
+    }
+
+    #[test]
+    fn test_preview_move() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        let preview = game.preview_move(19).unwrap();
+        assert_eq!(preview.get_field(19), Ok(Some(Disc::Black)));
+        assert_eq!(preview.get_field(27), Ok(Some(Disc::Black)));
+        assert_eq!(game.board(), &Board::new());
+        assert!(game.history().is_empty());
+        assert_eq!(game.current_disc(), Disc::Black);
+
+        assert_eq!(game.preview_move(0), Err(GameError::InvalidMove));
+        assert_eq!(game.preview_move(64), Err(GameError::InvalidMove));
+
+        game.step().unwrap();
+        assert_eq!(game.preview_move(18).map(|board| board.count_discs(Disc::White)), Ok(3));
+
+        game.run().unwrap();
+        assert_eq!(game.preview_move(0), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn test_available_moves_strictly_increasing() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        let mut positions = 0;
+        while !game.is_over() {
+            for moves in [game.available_moves(), game.legal_for(game.current_disc().opposite())] {
+                assert!(moves.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", moves);
+            }
+            game.step().unwrap();
+            positions += 1;
+        }
+        assert!(positions > 50);
+    }
+
+    #[test]
+    fn test_pass() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.pass(), Err(GameError::InvalidMove));
+        assert!(game.history().is_empty());
+        assert_eq!(game.current_disc(), Disc::Black);
+
+        // black is shut out here but white can still move
+        for &mv in &[19, 18, 17, 9, 37, 16, 0, 2] {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert!(game.forced_pass());
+        assert!(!game.is_over());
+        assert_eq!(game.pass(), Ok(()));
+        assert_eq!(game.history().last(), Some(&Turn::Pass(Disc::Black)));
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.consecutive_passes(), 1);
+
+        game.run().unwrap();
+        assert_eq!(game.pass(), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn test_play() {
+        let mut game = Game::new(
+            Box::new(PassingPlayer),
+            Box::new(PassingPlayer),
+        );
+        assert_eq!(game.play(0), Err(GameError::InvalidMove));
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert_eq!(game.play(19), Ok(()));
+        assert_eq!(game.play(18), Ok(()));
+        assert_eq!(game.to_transcript(), "d3 c3");
+        assert_eq!(game.current_disc(), Disc::Black);
+
+        game.resign(Disc::Black);
+        assert_eq!(game.play(17), Err(GameError::GameOver));
+    }
+
+    #[test]
+    fn test_set_board_and_current() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        game.step().unwrap();
+
+        // only a1 is left, and only white can take it
+        let placements: Vec<(usize, Disc)> = (1..64)
+            .map(|index| (index, if index == 1 { Disc::Black } else { Disc::White }))
+            .collect();
+        let board = Board::set_position(&placements).unwrap();
+        game.set_board(board.clone());
+        assert_eq!(game.board(), &board);
+        assert!(game.history().is_empty());
+        assert_eq!(game.board_at(0), Some(board));
+
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.available_moves(), [0]);
+        game.set_current(Disc::Black);
+        assert!(game.available_moves().is_empty());
+        assert!(!game.is_over());
+        assert_eq!(game.outcome(), None);
+
+        assert_eq!(game.step(), Ok(Turn::Pass(Disc::Black)));
+        assert_eq!(game.step(), Ok(Turn::Move { disc: Disc::White, index: 0 }));
+        assert!(game.is_over());
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::White)));
+        assert_eq!(game.current_score(), (0, 64));
+
+        // a new position starts a new game, even after a resignation
+        game.set_board(Board::new());
+        game.resign(Disc::Black);
+        assert!(game.is_over());
+        game.set_board(Board::new());
+        assert!(!game.is_over());
+        assert_eq!(game.outcome(), None);
+    }
+
+    #[test]
+    fn test_ended_full() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert!(!game.ended_full());
+        game.run().unwrap();
+        assert_eq!(game.ended_full(), game.board().is_full());
+
+        // white has been wiped out with most of the board still empty
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        game.set_board(Board::set_position(&[(27, Disc::Black), (28, Disc::Black)]).unwrap());
+        assert!(game.is_over());
+        assert_eq!(game.outcome(), Some(GameOutcome::Winner(Disc::Black)));
+        assert!(!game.ended_full());
+    }
+
+    #[test]
+    fn test_render() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        let panel = game.render();
+        assert!(panel.starts_with(&game.board().display_with_coordinates()));
+        assert!(panel.contains("Black to move"));
+        assert!(panel.contains("2 - 2"));
+
+        for &mv in &[19, 18, 17, 9, 37, 16, 0, 2] {
+            assert_eq!(game.apply_current(mv), Ok(()));
+            game.advance_turn();
+        }
+        assert!(game.render().contains("Black to move (must pass)"));
+
+        game.run().unwrap();
+        let expected = match game.outcome().unwrap() {
+            GameOutcome::Winner(disc) => format!("Game over: {:?} wins", disc),
+            GameOutcome::Tie => "Game over: tie".to_string(),
+        };
+        assert!(game.render().ends_with(&format!("{}\n", expected)));
+    }
+
+    #[test]
+    fn test_swap_players() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(PassingPlayer),
+        );
+        game.step().unwrap();
+        game.resign(Disc::White);
+        assert!(game.is_over());
+
+        game.swap_players();
+        assert_eq!(game.board(), &StandardBoard::new());
+        assert!(game.history().is_empty());
+        assert_eq!(game.current_disc(), Disc::Black);
+        assert!(!game.is_over());
+        // the passing player now has black, the move-making one white
+        assert_eq!(game.current_player().select_move(game.board(), Disc::Black), None);
+        assert_eq!(game.step(), Err(GameError::InvalidMove));
+        game.set_current(Disc::White);
+        assert_eq!(
+            game.current_player().select_move(game.board(), Disc::White),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut game = Game::new(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+        );
+        assert_eq!(game.evaluate(&DiscDifference), 0);
+        // after d3 black leads 4 to 1, and it is white's turn
+        game.step().unwrap();
+        assert_eq!(game.evaluate(&DiscDifference), -3);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut game = Game::from_transcript(
+            Box::new(ValidPlayer),
+            Box::new(ValidPlayer),
+            "d3 c3 b3",
+        )
+        .unwrap();
+        let snap = game.snapshot();
+        assert_eq!(snap.current, Disc::White);
+        assert_eq!(&snap.board, game.board());
+        assert_eq!(snap.ply, 3);
+
+        for _ in 0..5 {
+            game.step().unwrap();
+        }
+        game.resign(Disc::Black);
+        assert_ne!(game.board(), &snap.board);
+
+        game.restore(snap.clone());
+        assert_eq!(game.snapshot(), snap);
+        assert_eq!(game.board(), &snap.board);
+        assert_eq!(game.current_disc(), Disc::White);
+        assert!(!game.is_over());
+
+        // the game still knows how it got here
+        assert_eq!(game.ply(), 3);
+        assert_eq!(game.to_transcript(), "d3 c3 b3");
+        assert_eq!(game.board_at(3).as_ref(), Some(&snap.board));
+        assert_eq!(game.undo_last(), Ok(()));
+        assert_eq!(game.ply(), 2);
+        assert_eq!(game.current_disc(), Disc::Black);
+
+        // a history that no longer leads back to the snapshot starts over there
+        game.set_board(Board::new());
+        game.restore(snap.clone());
+        assert_eq!(game.snapshot().board, snap.board);
+        assert_eq!(game.current_disc(), Disc::White);
+        assert_eq!(game.ply(), 0);
+    }
 }
@@ -1,4 +1,20 @@
+//! Othello rules and players.
+//!
+//! Builds without `std` when default features are off. The `alloc` feature adds the
+//! methods that return `String`s and `Vec`s, and `std` (the default) adds `game`,
+//! which needs a clock for time limits.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod board;
 pub mod disc;
 pub mod player;
-pub mod game;
\ No newline at end of file
+#[cfg(feature = "std")]
+pub mod game;
+pub mod evaluator;
+pub mod analysis;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -1,6 +1,27 @@
 use crate::board::Board;
 use crate::disc::Disc;
+use core::time::Duration;
 
-pub trait Player {
-    fn select_move(&self, board: &Board, disc: Disc) -> usize;
-}
\ No newline at end of file
+/// Players are `Send` so that a game can ask a time-limited player for its move on
+/// another thread.
+pub trait Player: Send {
+    /// Returns the index to play, or `None` to pass when `disc` has no legal move.
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize>;
+
+    /// How long `select_move` may take. A player with a limit is asked on a thread of
+    /// its own, and the game applies its `TimeoutPolicy` if no answer has come by the
+    /// deadline. No limit by default.
+    fn time_limit(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl<P: Player + Sync + ?Sized> Player for &P {
+    fn select_move(&self, board: &Board, disc: Disc) -> Option<usize> {
+        (**self).select_move(board, disc)
+    }
+
+    fn time_limit(&self) -> Option<Duration> {
+        (**self).time_limit()
+    }
+}
@@ -0,0 +1,104 @@
+use crate::board::Board;
+use crate::disc::Disc;
+use crate::game::{Game, GameOutcome};
+use crate::player::Player;
+use wasm_bindgen::prelude::*;
+
+/// Never picks a move; the browser plays both sides through `WebGame::play`.
+struct Remote;
+
+impl Player for Remote {
+    fn select_move(&self, _: &Board, _: Disc) -> Option<usize> {
+        None
+    }
+}
+
+/// A game driven from JavaScript, one move at a time.
+#[wasm_bindgen]
+pub struct WebGame {
+    game: Game<'static>,
+}
+
+#[wasm_bindgen]
+impl WebGame {
+    /// A game at the standard opening with black to move.
+    pub fn new_game() -> WebGame {
+        WebGame {
+            game: Game::new(Box::new(Remote), Box::new(Remote)),
+        }
+    }
+
+    /// The legal moves of the side to move, as ascending indices.
+    pub fn legal_moves(&self) -> Vec<usize> {
+        self.game.available_moves()
+    }
+
+    /// Plays `index` for the side to move and returns whether it was legal. When the
+    /// other side then has no move, its pass is played too.
+    pub fn play(&mut self, index: usize) -> bool {
+        if self.game.play(index).is_err() {
+            return false;
+        }
+        let _ = self.game.pass();
+        true
+    }
+
+    /// The board in `Board::to_compact` form: `B`, `W` or `-` per square, then a
+    /// space and `b` or `w` for the side to move.
+    pub fn board_string(&self) -> String {
+        self.game.board().to_compact(self.game.current_disc())
+    }
+
+    /// `"black"` or `"white"` for the winner, `"tie"`, or nothing while the game is on.
+    pub fn outcome(&self) -> Option<String> {
+        outcome_label(self.game.outcome()).map(String::from)
+    }
+}
+
+fn outcome_label(outcome: Option<GameOutcome>) -> Option<&'static str> {
+    match outcome? {
+        GameOutcome::Winner(Disc::Black) => Some("black"),
+        GameOutcome::Winner(Disc::White) => Some("white"),
+        GameOutcome::Tie => Some("tie"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcome_labels() {
+        assert_eq!(outcome_label(None), None);
+        assert_eq!(
+            outcome_label(Some(GameOutcome::Winner(Disc::Black))),
+            Some("black")
+        );
+        assert_eq!(
+            outcome_label(Some(GameOutcome::Winner(Disc::White))),
+            Some("white")
+        );
+        assert_eq!(outcome_label(Some(GameOutcome::Tie)), Some("tie"));
+    }
+
+    #[test]
+    fn web_game_plays_moves() {
+        let mut game = WebGame::new_game();
+        assert_eq!(game.legal_moves(), [19, 26, 37, 44]);
+        assert_eq!(
+            game.board_string(),
+            "---------------------------WB------BW--------------------------- b"
+        );
+        assert!(!game.play(0));
+        assert!(game.play(19));
+        assert_eq!(&game.board_string()[16..32], "---B-------BB---");
+        assert!(game.board_string().ends_with(" w"));
+        assert_eq!(game.legal_moves(), [18, 20, 34]);
+        assert_eq!(game.outcome(), None);
+
+        while let Some(&index) = game.legal_moves().first() {
+            assert!(game.play(index));
+        }
+        assert!(game.outcome().is_some());
+    }
+}